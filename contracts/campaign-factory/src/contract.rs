@@ -1,10 +1,11 @@
 use crate::error::ContractError;
+use crate::migrations::migrate_legacy_campaigns;
 use crate::state::{
-    Config, ConfigResponse, CreateCampaign, FactoryCampaign, ADDR_CAMPAIGNS, CONFIG,
-    NUMBER_OF_CAMPAIGNS,
+    CampaignSelection, Config, ConfigResponse, CreateCampaign, FactoryCampaign, ADDR_CAMPAIGNS,
+    CONFIG, NUMBER_OF_CAMPAIGNS,
 };
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     state::CAMPAIGNS,
 };
 // use campaign::msg::ExecuteMsg as CampaignExecuteMsg;
@@ -17,12 +18,15 @@ use cosmwasm_std::{
     to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
     QueryRequest, Reply, ReplyOn, Response, StdResult, SubMsg, WasmMsg, WasmQuery,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 use cw_utils::parse_reply_instantiate_data;
+use semver::Version;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:campaign-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_MIGRATE_LIMIT: u32 = 10;
+const MAX_MIGRATE_LIMIT: u32 = 30;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -58,6 +62,45 @@ pub fn instantiate(
     ]))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous = get_contract_version(deps.storage)?;
+
+    // only campaign factory contract can be migrated
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::CannotMigrate {
+            previous_contract: previous.contract,
+        });
+    }
+
+    // not allow downgrade contract version
+    let previous_version: Version = previous.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if previous_version > new_version {
+        return Err(ContractError::CannotMigrateVersion {
+            previous_version: previous.version,
+            new_version: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    // campaigns stored with single reward token and collection are rewritten in current shape
+    let legacy_code_id = match msg.legacy_code_id {
+        Some(legacy_code_id) => legacy_code_id,
+        None => CONFIG.load(deps.storage)?.campaign_code_id,
+    };
+    let migrated = migrate_legacy_campaigns(deps.storage, legacy_code_id)?;
+
+    // set new version to contract
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "migrate"),
+        ("previous_version", &previous.version),
+        ("new_version", CONTRACT_VERSION),
+        ("migrated_campaigns", &migrated.to_string()),
+    ]))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -81,6 +124,11 @@ pub fn execute(
         ExecuteMsg::CreateCampaign { create_campaign } => {
             execute_create_campaign(deps, env, info, create_campaign)
         }
+        ExecuteMsg::MigrateCampaigns {
+            new_code_id,
+            campaigns,
+            msg,
+        } => execute_migrate_campaigns(deps, env, info, new_code_id, campaigns, msg),
     }
 }
 
@@ -145,6 +193,20 @@ pub fn execute_create_campaign(
         return Err(ContractError::Unauthorized {});
     }
 
+    let allowed_collections_str = create_campaign
+        .allowed_collections
+        .iter()
+        .map(|collection| collection.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    let reward_token_info_str = create_campaign
+        .reward_tokens
+        .iter()
+        .map(|token_info| token_info.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
     Ok(Response::new()
         .add_attributes(vec![
             ("method", "create_campaign"),
@@ -164,14 +226,8 @@ pub fn execute_create_campaign(
                 "limit_per_staker",
                 create_campaign.limit_per_staker.to_string().as_str(),
             ),
-            (
-                "reward_token_info",
-                &format!("{}", create_campaign.reward_token_info),
-            ),
-            (
-                "allowed_collection",
-                create_campaign.allowed_collection.as_str(),
-            ),
+            ("reward_token_info", &reward_token_info_str),
+            ("allowed_collections", &allowed_collections_str),
             (
                 "lockup_term",
                 &format!("{:?}", &create_campaign.lockup_term),
@@ -192,22 +248,95 @@ pub fn execute_create_campaign(
                     campaign_image: create_campaign.campaign_image,
                     campaign_description: create_campaign.campaign_description,
                     limit_per_staker: create_campaign.limit_per_staker,
-                    reward_token_info: create_campaign.reward_token_info,
-                    allowed_collection: create_campaign.allowed_collection,
+                    reward_tokens: create_campaign.reward_tokens,
+                    allowed_collections: create_campaign.allowed_collections,
                     lockup_term: create_campaign.lockup_term,
                     start_time: create_campaign.start_time,
                     end_time: create_campaign.end_time,
+                    trait_multipliers: create_campaign.trait_multipliers,
                 })?,
             }),
             reply_on: ReplyOn::Success,
         }))
 }
 
+// Only owner can execute it, factory is admin of all campaigns
+pub fn execute_migrate_campaigns(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    new_code_id: u64,
+    campaigns: CampaignSelection,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // select campaign ids, max MAX_MIGRATE_LIMIT campaigns per execution to stay within gas limit
+    let campaign_ids = match campaigns {
+        CampaignSelection::Ids { campaign_ids } => {
+            if campaign_ids.len() > MAX_MIGRATE_LIMIT as usize {
+                return Err(ContractError::TooManyCampaigns {
+                    max: MAX_MIGRATE_LIMIT,
+                });
+            }
+            campaign_ids
+        }
+        CampaignSelection::Range { start_after, limit } => {
+            let start_after = start_after.unwrap_or(0);
+            let limit = limit
+                .unwrap_or(DEFAULT_MIGRATE_LIMIT)
+                .min(MAX_MIGRATE_LIMIT) as usize;
+            let campaign_count = NUMBER_OF_CAMPAIGNS.load(deps.storage)?;
+
+            ((start_after + 1)..=campaign_count)
+                .take(limit)
+                .collect::<Vec<u64>>()
+        }
+    };
+
+    let mut res = Response::new();
+
+    for campaign_id in campaign_ids.iter() {
+        let mut campaign = CAMPAIGNS.load(deps.storage, *campaign_id)?;
+
+        // prepare message to migrate campaign
+        res = res.add_message(WasmMsg::Migrate {
+            contract_addr: campaign.campaign_addr.to_string(),
+            new_code_id,
+            msg: msg.clone(),
+        });
+
+        // update current code id of campaign
+        campaign.code_id = new_code_id;
+        CAMPAIGNS.save(deps.storage, *campaign_id, &campaign)?;
+    }
+
+    // the last campaign id migrated is start_after of next page
+    let last_campaign_id = campaign_ids
+        .last()
+        .map_or(String::new(), |id| id.to_string());
+
+    Ok(res.add_attributes([
+        ("method", "migrate_campaigns"),
+        ("new_code_id", &new_code_id.to_string()),
+        ("campaign_ids", &format!("{:?}", campaign_ids)),
+        ("last_campaign_id", &last_campaign_id),
+    ]))
+}
+
 /// This just stores the result for future query
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
     let reply = parse_reply_instantiate_data(msg).unwrap();
 
+    // campaign is instantiated with campaign code id in config
+    let config: Config = CONFIG.load(deps.storage)?;
+
     let campaign_contract = &reply.contract_address;
     let campaign_info: CampaignInfo =
         query_pair_info_from_pair(&deps.querier, Addr::unchecked(campaign_contract))?;
@@ -220,8 +349,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
         &FactoryCampaign {
             owner: campaign_info.owner.clone(),
             campaign_addr: deps.api.addr_validate(campaign_contract)?,
-            reward_token: campaign_info.reward_token.info,
-            allowed_collection: campaign_info.allowed_collection,
+            reward_tokens: campaign_info
+                .reward_tokens
+                .into_iter()
+                .map(|reward_token| reward_token.info)
+                .collect(),
+            allowed_collections: campaign_info.allowed_collections,
+            code_id: config.campaign_code_id,
         },
     )?;
 
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use semver::Error as SemVerError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -8,4 +9,25 @@ pub enum ContractError {
 
     #[error("## Unauthorized ##")]
     Unauthorized {},
+
+    #[error("## Max {max:?} campaigns can be migrated at once ##")]
+    TooManyCampaigns { max: u32 },
+
+    #[error("## Cannot migrate from contract {previous_contract} ##")]
+    CannotMigrate { previous_contract: String },
+
+    #[error("## Cannot migrate from version {previous_version} to {new_version} ##")]
+    CannotMigrateVersion {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("## Invalid contract version: {0} ##")]
+    SemVer(String),
+}
+
+impl From<SemVerError> for ContractError {
+    fn from(err: SemVerError) -> Self {
+        Self::SemVer(err.to_string())
+    }
 }
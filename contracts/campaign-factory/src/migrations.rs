@@ -0,0 +1,56 @@
+use cosmwasm_std::{StdResult, Storage};
+
+use crate::state::{FactoryCampaign, CAMPAIGNS, NUMBER_OF_CAMPAIGNS};
+use campaign::state::AllowedCollection;
+
+/// Rewrites campaigns stored with a single reward token and collection, which fail to load as
+/// current FactoryCampaign, returns the number of rewritten campaigns
+pub fn migrate_legacy_campaigns(storage: &mut dyn Storage, legacy_code_id: u64) -> StdResult<u64> {
+    let campaign_count = NUMBER_OF_CAMPAIGNS.load(storage)?;
+    let mut migrated = 0u64;
+
+    for campaign_id in 1..=campaign_count {
+        if CAMPAIGNS.load(storage, campaign_id).is_ok() {
+            continue;
+        }
+
+        let campaign = v1::CAMPAIGNS.load(storage, campaign_id)?;
+
+        // single collection had the same weight as a collection with weight 1
+        CAMPAIGNS.save(
+            storage,
+            campaign_id,
+            &FactoryCampaign {
+                owner: campaign.owner,
+                campaign_addr: campaign.campaign_addr,
+                reward_tokens: vec![campaign.reward_token],
+                allowed_collections: vec![AllowedCollection {
+                    collection_addr: campaign.allowed_collection.to_string(),
+                    weight: 1,
+                }],
+                code_id: legacy_code_id,
+            },
+        )?;
+        migrated += 1;
+    }
+
+    Ok(migrated)
+}
+
+// factory state before campaigns had many reward tokens and collections
+mod v1 {
+    use campaign::state::TokenInfo;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Map;
+
+    pub const CAMPAIGNS: Map<u64, FactoryCampaign> = Map::new("campaigns");
+
+    #[cw_serde]
+    pub struct FactoryCampaign {
+        pub owner: Addr,
+        pub campaign_addr: Addr,
+        pub reward_token: TokenInfo,
+        pub allowed_collection: Addr,
+    }
+}
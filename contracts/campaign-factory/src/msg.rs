@@ -1,5 +1,6 @@
-use crate::state::{ConfigResponse, CreateCampaign, FactoryCampaign};
+use crate::state::{CampaignSelection, ConfigResponse, CreateCampaign, FactoryCampaign};
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Binary;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -9,6 +10,12 @@ pub struct InstantiateMsg {
     pub allow_create_for_all: bool,
 }
 
+#[cw_serde]
+pub struct MigrateMsg {
+    /// Code ID of campaigns stored before it was tracked, default campaign code ID in config
+    pub legacy_code_id: Option<u64>,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// UpdateConfig update relevant code IDs
@@ -19,6 +26,12 @@ pub enum ExecuteMsg {
     },
     /// CreateCampaign instantiates pair contract
     CreateCampaign { create_campaign: CreateCampaign },
+    /// MigrateCampaigns migrates selected campaigns to new code ID
+    MigrateCampaigns {
+        new_code_id: u64,
+        campaigns: CampaignSelection,
+        msg: Binary,
+    },
 }
 
 #[cw_serde]
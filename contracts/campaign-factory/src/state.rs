@@ -1,4 +1,7 @@
-use campaign::state::{AssetToken, LockupTerm, TokenInfo};
+use campaign::state::{AllowedCollection, LockupTerm, TokenInfo, TraitMultiplier};
+
+// nft metadata is defined in campaign, which reads it to calculate reward weight of nft
+pub use campaign::state::{Metadata, Trait};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
@@ -22,8 +25,9 @@ pub struct ConfigResponse {
 pub struct FactoryCampaign {
     pub owner: Addr,
     pub campaign_addr: Addr,
-    pub reward_token: TokenInfo,
-    pub allowed_collection: Addr,
+    pub reward_tokens: Vec<TokenInfo>,
+    pub allowed_collections: Vec<AllowedCollection>,
+    pub code_id: u64, // current code id of campaign
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -32,32 +36,16 @@ pub const NUMBER_OF_CAMPAIGNS: Item<u64> = Item::new("number_of_campaigns");
 pub const ADDR_CAMPAIGNS: Item<Vec<String>> = Item::new("addr_campaigns");
 
 #[cw_serde]
-pub struct Trait {
-    pub display_type: Option<String>,
-    pub trait_type: String,
-    pub value: String,
-}
-
-// see: https://docs.opensea.io/docs/metadata-standards
-#[cw_serde]
-#[derive(Default)]
-pub struct Metadata {
-    pub image: Option<String>,
-    pub image_data: Option<String>,
-    pub external_url: Option<String>,
-    pub description: Option<String>,
-    pub name: Option<String>,
-    pub attributes: Option<Vec<Trait>>,
-    pub background_color: Option<String>,
-    pub animation_url: Option<String>,
-    pub youtube_url: Option<String>,
-    /// This is how much the minter takes as a cut when sold
-    /// royalties are owed on this token if it is Some
-    pub royalty_percentage: Option<u64>,
-    /// The payment address, may be different to or the same
-    /// as the minter addr
-    /// question: how do we validate this?
-    pub royalty_payment_address: Option<String>,
+pub enum CampaignSelection {
+    // migrate campaigns with these ids
+    Ids {
+        campaign_ids: Vec<u64>,
+    },
+    // migrate campaigns page by page
+    Range {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -69,7 +57,8 @@ pub struct CreateCampaign {
     pub start_time: u64,
     pub end_time: u64,
     pub limit_per_staker: u64,
-    pub reward_token_info: AssetToken,
-    pub allowed_collection: String,
+    pub reward_tokens: Vec<TokenInfo>,
+    pub allowed_collections: Vec<AllowedCollection>,
     pub lockup_term: Vec<LockupTerm>,
+    pub trait_multipliers: Vec<TraitMultiplier>,
 }
@@ -1,6 +1,8 @@
 #[cfg(test)]
 pub mod env {
-    use cosmwasm_std::{Addr, Empty};
+    use cosmwasm_std::{
+        Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Uint128,
+    };
     use cw20::MinterResponse;
     use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 
@@ -20,15 +22,21 @@ pub mod env {
     };
 
     use cw721_base::msg::InstantiateMsg as Cw721InstantiateMsg;
+    use cw721_base::{ContractError as Cw721ContractError, Cw721Contract};
 
-    use campaign::contract::{execute as Execute, instantiate as Instantiate, query as Query};
+    use campaign::contract::{
+        execute as Execute, instantiate as Instantiate, migrate as Migrate, query as Query,
+    };
 
     use crate::msg::InstantiateMsg as FactoryInstantiateMsg;
+    use crate::state::Metadata;
 
     pub const ADMIN: &str = "aura1000000000000000000000000000000000admin";
     pub const USER_1: &str = "aura1000000000000000000000000000000000user1";
     pub const USER_2: &str = "aura1000000000000000000000000000000000user2";
     pub const USER_3: &str = "aura1000000000000000000000000000000000user3";
+    pub const NATIVE_DENOM: &str = "ueaura";
+    pub const NATIVE_BALANCE: u128 = 1_000_000_000;
 
     pub struct ContractInfo {
         pub contract_addr: String,
@@ -39,7 +47,14 @@ pub mod env {
         AppBuilder::new().build(|router, _, storage| {
             router
                 .bank
-                .init_balance(storage, &Addr::unchecked(ADMIN), vec![])
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(ADMIN),
+                    vec![Coin {
+                        denom: NATIVE_DENOM.to_string(),
+                        amount: Uint128::new(NATIVE_BALANCE),
+                    }],
+                )
                 .unwrap();
         })
     }
@@ -52,8 +67,8 @@ pub mod env {
     }
 
     // campaign contract
-    fn contract_template() -> Box<dyn Contract<Empty>> {
-        let contract = ContractWrapper::new(Execute, Instantiate, Query);
+    pub fn contract_template() -> Box<dyn Contract<Empty>> {
+        let contract = ContractWrapper::new(Execute, Instantiate, Query).with_migrate(Migrate);
         Box::new(contract)
     }
 
@@ -70,6 +85,35 @@ pub mod env {
         Box::new(contract)
     }
 
+    // collection contract with on-chain metadata
+    type MetadataCollection<'a> = Cw721Contract<'a, Option<Metadata>, Empty, Empty, Empty>;
+
+    pub fn metadata_collection_contract_template() -> Box<dyn Contract<Empty>> {
+        fn execute(
+            deps: DepsMut,
+            env: Env,
+            info: MessageInfo,
+            msg: cw721_base::ExecuteMsg<Option<Metadata>, Empty>,
+        ) -> Result<Response, Cw721ContractError> {
+            MetadataCollection::default().execute(deps, env, info, msg)
+        }
+
+        fn instantiate(
+            deps: DepsMut,
+            env: Env,
+            info: MessageInfo,
+            msg: Cw721InstantiateMsg,
+        ) -> StdResult<Response> {
+            MetadataCollection::default().instantiate(deps, env, info, msg)
+        }
+
+        fn query(deps: Deps, env: Env, msg: cw721_base::QueryMsg<Empty>) -> StdResult<Binary> {
+            MetadataCollection::default().query(deps, env, msg)
+        }
+
+        Box::new(ContractWrapper::new(execute, instantiate, query))
+    }
+
     pub fn instantiate_contracts() -> (App, Vec<ContractInfo>) {
         // Create a new app instance
         let mut app = mock_app();
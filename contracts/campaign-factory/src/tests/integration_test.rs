@@ -11,25 +11,53 @@ mod tests {
     // 8. withdraw remaining reward
     mod execute_proper_operation {
         use crate::{
+            contract::{
+                execute as factory_execute, instantiate as factory_instantiate,
+                migrate as factory_migrate, query as factory_query,
+            },
             msg::QueryMsg,
-            state::{ConfigResponse, CreateCampaign, FactoryCampaign, Metadata},
+            state::{
+                CampaignSelection, ConfigResponse, CreateCampaign, FactoryCampaign, Metadata,
+                NUMBER_OF_CAMPAIGNS,
+            },
             tests::{
-                env_setup::env::{instantiate_contracts, ADMIN, USER_1, USER_2, USER_3},
+                env_setup::env::{
+                    contract_template, instantiate_contracts,
+                    metadata_collection_contract_template, ADMIN, NATIVE_BALANCE, NATIVE_DENOM,
+                    USER_1, USER_2, USER_3,
+                },
                 integration_test::tests::MOCK_1000_TOKEN_AMOUNT,
             },
         };
         use campaign::state::{
-            AssetToken, CampaignInfo, LockupTerm, NftInfo, NftKey, NftStake, RewardRate,
-            StakerRewardAssetInfo, TokenInfo,
+            AllowedCollection, AssetToken, CampaignInfo, EarlyUnstakePenalty, LockupTerm, NftInfo,
+            NftKey, NftPendingReward, NftStake, PendingTransfer, ResetPoolProgressResponse,
+            RewardToken, SimulateStakeResponse, StakerPendingRewardResponse, StakerRewardAssetInfo,
+            StatusResponse, TermResetProgress, TermRewardIndex, TokenInfo, Trait, TraitMultiplier,
+            CAMPAIGN_INFO, NFTS, NUMBER_OF_NFTS, STAKERS_INFO, STATE_VERSION, WEIGHT_PRECISION,
         };
         use campaign::{
-            msg::{ExecuteMsg as CampaignExecuteMsg, QueryMsg as CampaignQueryMsg},
-            utils::{add_reward, calc_reward_in_time, sub_reward},
+            contract::{migrate as campaign_migrate, query as campaign_query},
+            migrations::{self, v1, v2, v4, LATEST_STATE_VERSION},
+            msg::{
+                ExecuteMsg as CampaignExecuteMsg, MigrateMsg as CampaignMigrateMsg,
+                QueryMsg as CampaignQueryMsg, ReceiveMsg as CampaignReceiveMsg,
+                ReceiveNftMsg as CampaignReceiveNftMsg, UpdateCampaignInfo,
+            },
+            utils::{accrue_reward, add_reward, calculate_reward, sub_reward},
+            ContractError,
         };
-        use cosmwasm_std::{Addr, BlockInfo, Empty, Uint128};
-        use cw20::{BalanceResponse, Cw20ExecuteMsg};
-        use cw721_base::MintMsg as Cw721MintMsg;
+        use cosmwasm_schema::cw_serde;
+        use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+        use cosmwasm_std::{
+            coins, from_binary, to_binary, Addr, Attribute, BlockInfo, Decimal, Decimal256, Empty,
+            Timestamp, Uint128,
+        };
+        use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20ReceiveMsg};
+        use cw721::{Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
+        use cw721_base::{InstantiateMsg as Cw721InstantiateMsg, MintMsg as Cw721MintMsg};
         use cw_multi_test::Executor;
+        use cw_storage_plus::Map;
 
         pub type Extension = Option<Metadata>;
         pub type Cw721ExecuteMsg = cw721_base::ExecuteMsg<Extension, Empty>;
@@ -264,21 +292,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 4,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10u64,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30u64,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -307,10 +338,14 @@ mod tests {
                 FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }
             );
 
@@ -343,26 +378,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::zero(),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::zero(),
+                        total_reward: Uint128::zero(),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::zero(),
-                    total_reward: Uint128::zero(),
-                    reward_per_second: Uint128::zero(),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -384,26 +427,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::zero(),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::zero(),
+                        total_reward: Uint128::zero(),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::zero(),
-                    total_reward: Uint128::zero(),
-                    reward_per_second: Uint128::zero(),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -425,10 +476,14 @@ mod tests {
                 vec![FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }]
             );
 
@@ -451,7 +506,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -472,7 +532,7 @@ mod tests {
 
             assert_eq!(
                 Uint128::from(MOCK_1000_TOKEN_AMOUNT),
-                campaign_info.reward_token.amount
+                campaign_info.reward_tokens[0].amount
             );
 
             // query balance of ADMIN in cw20 base token contract
@@ -513,6 +573,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 10,
                 },
@@ -548,11 +609,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -579,8 +643,8 @@ mod tests {
                         key: 1,
                         lockup_term: 10
                     }],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 }
             );
 
@@ -610,11 +674,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(3000u128),
+                    pending_reward: vec![Uint128::from(3000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -626,6 +693,7 @@ mod tests {
             // stake nft token_id 2
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["2".to_string()],
                     lockup_term: 10,
                 },
@@ -661,11 +729,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(3000u128),
+                    pending_reward: vec![Uint128::from(3000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -693,11 +764,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -732,11 +806,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(12000u128),
+                    pending_reward: vec![Uint128::from(12000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -764,11 +841,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(9000u128),
+                    pending_reward: vec![Uint128::from(9000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -801,37 +881,32 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 }
             );
 
-            // reward rates
-            let reward_rates: Vec<RewardRate> = app
+            // reward index of lockup term
+            let term_reward_index: TermRewardIndex = app
                 .wrap()
                 .query_wasm_smart(
                     "contract3",
-                    &CampaignQueryMsg::TermRewardRates { term_value: 10 },
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
                 )
                 .unwrap();
 
-            assert_eq!(
-                reward_rates,
-                vec![
-                    RewardRate {
-                        timestamp: 1571797439,
-                        rate: 1
-                    },
-                    RewardRate {
-                        timestamp: 1571797440,
-                        rate: 2
-                    }
-                ]
-            );
+            assert_eq!(term_reward_index.total_weight, 200);
+            assert_eq!(term_reward_index.last_update, 1571797440);
 
             // USER_1 claim reward msg
             let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
-                amount: Uint128::from(21000u128),
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(21000u128),
+                }]),
+                recipient: None,
             };
 
             // Execute claim reward
@@ -844,31 +919,16 @@ mod tests {
 
             assert!(response.is_ok());
 
-            let reward_rates: Vec<RewardRate> = app
+            let term_reward_index: TermRewardIndex = app
                 .wrap()
                 .query_wasm_smart(
                     "contract3",
-                    &CampaignQueryMsg::TermRewardRates { term_value: 10 },
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
                 )
                 .unwrap();
 
-            assert_eq!(
-                reward_rates,
-                vec![
-                    RewardRate {
-                        timestamp: 1571797439,
-                        rate: 1
-                    },
-                    RewardRate {
-                        timestamp: 1571797440,
-                        rate: 2
-                    },
-                    RewardRate {
-                        timestamp: 1571797446,
-                        rate: 2
-                    }
-                ]
-            );
+            assert_eq!(term_reward_index.total_weight, 200);
+            assert_eq!(term_reward_index.last_update, 1571797446);
 
             // get staker info
             let staker_info: StakerRewardAssetInfo = app
@@ -894,8 +954,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(21000u128),
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(21000u128)],
                 }
             );
 
@@ -918,11 +978,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -950,11 +1013,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -989,11 +1055,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(4500u128),
+                    pending_reward: vec![Uint128::from(4500u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -1021,11 +1090,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(4500u128),
+                    pending_reward: vec![Uint128::from(4500u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -1071,8 +1143,8 @@ mod tests {
                         key: 2,
                         lockup_term: 10
                     }],
-                    reward_debt: Uint128::from(4500u128),
-                    reward_claimed: Uint128::from(21000u128),
+                    reward_debt: vec![Uint128::from(4500u128)],
+                    reward_claimed: vec![Uint128::from(21000u128)],
                 }
             );
 
@@ -1095,11 +1167,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(4500u128),
+                    pending_reward: vec![Uint128::from(4500u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -1108,40 +1183,25 @@ mod tests {
                 }
             );
 
-            let reward_rates: Vec<RewardRate> = app
+            let term_reward_index: TermRewardIndex = app
                 .wrap()
                 .query_wasm_smart(
                     "contract3",
-                    &CampaignQueryMsg::TermRewardRates { term_value: 10 },
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
                 )
                 .unwrap();
 
-            assert_eq!(
-                reward_rates,
-                vec![
-                    RewardRate {
-                        timestamp: 1571797439,
-                        rate: 1
-                    },
-                    RewardRate {
-                        timestamp: 1571797440,
-                        rate: 2
-                    },
-                    RewardRate {
-                        timestamp: 1571797446,
-                        rate: 2
-                    }
-                ]
-            );
+            assert_eq!(term_reward_index.total_weight, 100);
+            assert_eq!(term_reward_index.last_update, start_time_2 + 9);
 
             // get staker total pending reward
-            let total_pending_reward: Uint128 = app
+            let total_pending_reward: Vec<AssetToken> = app
                 .wrap()
                 .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
                 .unwrap();
 
             // token_id 2 = 4500, reward_debt USER_1 = 4500(token_id 1 unstake transerfered)
-            assert_eq!(total_pending_reward, Uint128::from(9000u128));
+            assert_eq!(total_pending_reward[0].amount, Uint128::from(9000u128));
 
             // increase 80 second to ended campaign
             app.set_block(BlockInfo {
@@ -1150,31 +1210,16 @@ mod tests {
                 chain_id: app.block_info().chain_id,
             });
 
-            let reward_rates: Vec<RewardRate> = app
+            let term_reward_index: TermRewardIndex = app
                 .wrap()
                 .query_wasm_smart(
                     "contract3",
-                    &CampaignQueryMsg::TermRewardRates { term_value: 10 },
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
                 )
                 .unwrap();
 
-            assert_eq!(
-                reward_rates,
-                vec![
-                    RewardRate {
-                        timestamp: 1571797439,
-                        rate: 1
-                    },
-                    RewardRate {
-                        timestamp: 1571797440,
-                        rate: 2
-                    },
-                    RewardRate {
-                        timestamp: 1571797446,
-                        rate: 2
-                    }
-                ]
-            );
+            assert_eq!(term_reward_index.total_weight, 100);
+            assert_eq!(term_reward_index.last_update, start_time_2 + 9);
 
             // get nft info token_id 2
             let nft_info: NftInfo = app
@@ -1195,11 +1240,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(7500u128),
+                    pending_reward: vec![Uint128::from(7500u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -1226,19 +1274,19 @@ mod tests {
                         key: 2,
                         lockup_term: 10
                     }],
-                    reward_debt: Uint128::from(4500u128),
-                    reward_claimed: Uint128::from(21000u128)
+                    reward_debt: vec![Uint128::from(4500u128)],
+                    reward_claimed: vec![Uint128::from(21000u128)]
                 }
             );
 
             // get total pending reward
-            let total_pending_reward: Uint128 = app
+            let total_pending_reward: Vec<AssetToken> = app
                 .wrap()
                 .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
                 .unwrap();
 
             // token_id 2 = 7500, reward_debt USER_1 = 4500(token_id 1 unstake transerfered)
-            assert_eq!(total_pending_reward, Uint128::from(12000u128));
+            assert_eq!(total_pending_reward[0].amount, Uint128::from(12000u128));
 
             // withdraw remaining reward msg = 979000 - 12000
             let withdraw_reward_msg = CampaignExecuteMsg::WithdrawReward {};
@@ -1271,26 +1319,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info,
                         amount: Uint128::from(12000u128),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::from_ratio(10000u128, 1u128),
+                        total_reward: Uint128::from(1000000u128),
+                        total_reward_claimed: Uint128::from(21000u128),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::from(21000u128),
-                    total_reward: Uint128::from(1000000u128),
-                    reward_per_second: Uint128::from(10000u128),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -1312,18 +1368,18 @@ mod tests {
                         key: 2,
                         lockup_term: 10
                     }],
-                    reward_debt: Uint128::from(4500u128),
-                    reward_claimed: Uint128::from(21000u128),
+                    reward_debt: vec![Uint128::from(4500u128)],
+                    reward_claimed: vec![Uint128::from(21000u128)],
                 }
             );
 
             // get staker total pending reward
-            let total_pending_reward: Uint128 = app
+            let total_pending_reward: Vec<AssetToken> = app
                 .wrap()
                 .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
                 .unwrap();
 
-            assert_eq!(total_pending_reward, Uint128::from(12000u128));
+            assert_eq!(total_pending_reward[0].amount, Uint128::from(12000u128));
         }
 
         //         -------------- proper operation with multiple users ------------------
@@ -1530,21 +1586,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 4,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -1573,10 +1632,14 @@ mod tests {
                 FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }
             );
 
@@ -1598,27 +1661,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::zero(),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::zero(),
+                        total_reward: Uint128::zero(),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-
-                    total_reward_claimed: Uint128::zero(),
-                    total_reward: Uint128::zero(),
-                    reward_per_second: Uint128::zero(),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -1640,10 +1710,14 @@ mod tests {
                 vec![FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }]
             );
 
@@ -1666,7 +1740,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -1687,7 +1766,7 @@ mod tests {
 
             assert_eq!(
                 Uint128::from(MOCK_1000_TOKEN_AMOUNT),
-                campaign_info.reward_token.amount
+                campaign_info.reward_tokens[0].amount
             );
 
             // query balance of ADMIN in cw20 base token contract
@@ -1728,6 +1807,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 10,
                 },
@@ -1747,6 +1827,7 @@ mod tests {
             // stake nft token_id 2
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["2".to_string()],
                     lockup_term: 10,
                 },
@@ -1782,11 +1863,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -1814,11 +1898,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -1851,8 +1938,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -1866,6 +1953,7 @@ mod tests {
             // stake nft token_id 6
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["6".to_string()],
                     lockup_term: 30,
                 },
@@ -1906,8 +1994,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -1929,8 +2017,8 @@ mod tests {
                         key: 1,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -1944,6 +2032,7 @@ mod tests {
             // stake nft token_id 3
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["3".to_string()],
                     lockup_term: 30,
                 },
@@ -1988,8 +2077,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -2011,8 +2100,8 @@ mod tests {
                         key: 1,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::zero(),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::zero()],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
             // increase 5 second
@@ -2041,11 +2130,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(15000u128),
+                    pending_reward: vec![Uint128::from(15000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -2073,11 +2165,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(15000u128),
+                    pending_reward: vec![Uint128::from(15000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -2105,11 +2200,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "3".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(17500u128),
+                    pending_reward: vec![Uint128::from(17500u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_3,
@@ -2137,11 +2235,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(52500u128),
+                    pending_reward: vec![Uint128::from(52500u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_6,
@@ -2178,14 +2279,20 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
             // USER_1 claim reward msg
             let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
-                amount: Uint128::from(47500u128),
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(47500u128),
+                }]),
+                recipient: None,
             };
 
             // Execute claim reward
@@ -2217,11 +2324,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -2249,11 +2359,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -2281,11 +2394,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "3".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_3,
@@ -2322,8 +2438,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -2353,11 +2469,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "3".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(105000u128),
+                    pending_reward: vec![Uint128::from(105000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_3,
@@ -2385,11 +2504,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(122500u128),
+                    pending_reward: vec![Uint128::from(122500u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_6,
@@ -2409,54 +2531,67 @@ mod tests {
 
             // assert_eq!(term_reward_rates, vec![]);
 
-            // update admin
-            let update_admin_msg = CampaignExecuteMsg::UpdateAdmin {
+            // propose new admin
+            let propose_admin_msg = CampaignExecuteMsg::ProposeNewAdmin {
                 admin: USER_2.to_string(),
+                expires_at: None,
             };
 
-            // Execute reset pool
             let response = app.execute_contract(
                 Addr::unchecked(USER_1.to_string()),
                 Addr::unchecked("contract3"),
-                &update_admin_msg,
+                &propose_admin_msg,
                 &[],
             );
 
             // err with USER_1 is not admin
             assert!(response.is_err());
 
-            // update admin
-            let update_admin_msg = CampaignExecuteMsg::UpdateAdmin {
-                admin: USER_2.to_string(),
-            };
-
-            // Execute reset pool
             let response = app.execute_contract(
                 Addr::unchecked(ADMIN.to_string()),
                 Addr::unchecked("contract3"),
-                &update_admin_msg,
+                &propose_admin_msg,
+                &[],
+            );
+
+            assert!(response.is_ok());
+
+            // USER_2 accept admin
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptAdmin {},
                 &[],
             );
 
             assert!(response.is_ok());
 
-            // update admin
-            let update_admin_msg = CampaignExecuteMsg::UpdateAdmin {
+            // USER_2 propose ADMIN as admin again
+            let propose_admin_msg = CampaignExecuteMsg::ProposeNewAdmin {
                 admin: ADMIN.to_string(),
+                expires_at: None,
             };
 
-            // Execute reset pool
             let response = app.execute_contract(
                 Addr::unchecked(USER_2.to_string()),
                 Addr::unchecked("contract3"),
-                &update_admin_msg,
+                &propose_admin_msg,
+                &[],
+            );
+
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptAdmin {},
                 &[],
             );
 
             assert!(response.is_ok());
 
             // reset pool
-            let reset_pool_msg = CampaignExecuteMsg::ResetPool {};
+            let reset_pool_msg = CampaignExecuteMsg::ResetPool { limit: None };
 
             // Execute reset pool
             let response = app.execute_contract(
@@ -2498,11 +2633,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -2530,11 +2668,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -2562,11 +2703,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "3".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(105000u128),
+                    pending_reward: vec![Uint128::from(105000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_3,
@@ -2594,11 +2738,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(122500u128),
+                    pending_reward: vec![Uint128::from(122500u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_6,
@@ -2610,6 +2757,7 @@ mod tests {
             // stake nft token_id 7
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["7".to_string()],
                     lockup_term: 30,
                 },
@@ -2645,11 +2793,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "7".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_7,
@@ -2678,26 +2829,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::from(952500u128),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::from_ratio(10000u128, 1u128),
+                        total_reward: Uint128::from(1000000u128),
+                        total_reward_claimed: Uint128::from(47500u128),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::from(47500u128),
-                    total_reward: Uint128::from(1000000u128),
-                    reward_per_second: Uint128::from(10000u128),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -2720,11 +2879,14 @@ mod tests {
                     NftInfo {
                         key: 1,
                         token_id: "1".to_string(),
+                        collection_addr: Addr::unchecked(collection_contract.clone()),
+                        weight: 100,
                         owner: Addr::unchecked(USER_1.to_string()),
-                        pending_reward: Uint128::from(0u128),
+                        pending_reward: vec![Uint128::from(0u128)],
                         lockup_term: LockupTerm {
                             value: 10,
-                            percent: Uint128::from(30u128)
+                            percent: Uint128::from(30u128),
+                            early_unstake_penalty: None,
                         },
                         is_end_reward: true,
                         start_time: start_time_1,
@@ -2734,11 +2896,14 @@ mod tests {
                     NftInfo {
                         key: 2,
                         token_id: "2".to_string(),
+                        collection_addr: Addr::unchecked(collection_contract.clone()),
+                        weight: 100,
                         owner: Addr::unchecked(USER_1.to_string()),
-                        pending_reward: Uint128::from(0u128),
+                        pending_reward: vec![Uint128::from(0u128)],
                         lockup_term: LockupTerm {
                             value: 10,
-                            percent: Uint128::from(30u128)
+                            percent: Uint128::from(30u128),
+                            early_unstake_penalty: None,
                         },
                         is_end_reward: true,
                         start_time: start_time_2,
@@ -2767,11 +2932,14 @@ mod tests {
                     NftInfo {
                         key: 1,
                         token_id: "6".to_string(),
+                        collection_addr: Addr::unchecked(collection_contract.clone()),
+                        weight: 100,
                         owner: Addr::unchecked(USER_2.to_string()),
-                        pending_reward: Uint128::from(122500u128),
+                        pending_reward: vec![Uint128::from(122500u128)],
                         lockup_term: LockupTerm {
                             value: 30,
-                            percent: Uint128::from(70u128)
+                            percent: Uint128::from(70u128),
+                            early_unstake_penalty: None,
                         },
                         is_end_reward: true,
                         start_time: start_time_6,
@@ -2781,11 +2949,14 @@ mod tests {
                     NftInfo {
                         key: 2,
                         token_id: "3".to_string(),
+                        collection_addr: Addr::unchecked(collection_contract.clone()),
+                        weight: 100,
                         owner: Addr::unchecked(USER_1.to_string()),
-                        pending_reward: Uint128::from(105000u128),
+                        pending_reward: vec![Uint128::from(105000u128)],
                         lockup_term: LockupTerm {
                             value: 30,
-                            percent: Uint128::from(70u128)
+                            percent: Uint128::from(70u128),
+                            early_unstake_penalty: None,
                         },
                         is_end_reward: true,
                         start_time: start_time_3,
@@ -2795,11 +2966,14 @@ mod tests {
                     NftInfo {
                         key: 3,
                         token_id: "7".to_string(),
+                        collection_addr: Addr::unchecked(collection_contract.clone()),
+                        weight: 100,
                         owner: Addr::unchecked(USER_2.to_string()),
-                        pending_reward: Uint128::from(0u128),
+                        pending_reward: vec![Uint128::from(0u128)],
                         lockup_term: LockupTerm {
                             value: 30,
-                            percent: Uint128::from(70u128)
+                            percent: Uint128::from(70u128),
+                            early_unstake_penalty: None,
                         },
                         is_end_reward: false,
                         start_time: start_time_7,
@@ -2812,6 +2986,7 @@ mod tests {
             // stake nft token_id 8
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["8".to_string()],
                     lockup_term: 10,
                 },
@@ -2846,26 +3021,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::from(952500u128),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::from_ratio(10000u128, 1u128),
+                        total_reward: Uint128::from(1000000u128),
+                        total_reward_claimed: Uint128::from(47500u128),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::from(47500u128),
-                    total_reward: Uint128::from(1000000u128),
-                    reward_per_second: Uint128::from(10000u128),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -2897,8 +3080,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -2921,11 +3104,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "7".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_7,
@@ -2953,11 +3139,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "8".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_8,
@@ -2994,13 +3183,13 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
             // reset pool
-            let reset_pool_msg = CampaignExecuteMsg::ResetPool {};
+            let reset_pool_msg = CampaignExecuteMsg::ResetPool { limit: None };
 
             // Execute reset pool
             let response = app.execute_contract(
@@ -3022,6 +3211,7 @@ mod tests {
             // stake nft token_id 4
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["4".to_string()],
                     lockup_term: 30,
                 },
@@ -3063,11 +3253,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -3094,11 +3287,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -3126,11 +3322,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "3".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(105000u128),
+                    pending_reward: vec![Uint128::from(105000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_3,
@@ -3158,11 +3357,14 @@ mod tests {
                 NftInfo {
                     key: 4,
                     token_id: "4".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(35000u128),
+                    pending_reward: vec![Uint128::from(35000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_4,
@@ -3190,11 +3392,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(122500u128),
+                    pending_reward: vec![Uint128::from(122500u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_6,
@@ -3222,11 +3427,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "7".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(105000u128),
+                    pending_reward: vec![Uint128::from(105000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_7,
@@ -3254,11 +3462,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "8".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(30000u128),
+                    pending_reward: vec![Uint128::from(30000u128)],
                     lockup_term: LockupTerm {
                         value: 10,
-                        percent: Uint128::from(30u128)
+                        percent: Uint128::from(30u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_8,
@@ -3309,8 +3520,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -3342,8 +3553,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -3394,8 +3605,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -3442,8 +3653,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -3484,8 +3695,8 @@ mod tests {
                         key: 4,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::from(105000u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(105000u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -3518,11 +3729,14 @@ mod tests {
                 NftInfo {
                     key: 4,
                     token_id: "4".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(35000u128),
+                    pending_reward: vec![Uint128::from(35000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(70u128)
+                        percent: Uint128::from(70u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_4,
@@ -3622,8 +3836,8 @@ mod tests {
                 staked,
                 StakerRewardAssetInfo {
                     keys: vec![],
-                    reward_debt: Uint128::from(140000u128),
-                    reward_claimed: Uint128::from(47500u128)
+                    reward_debt: vec![Uint128::from(140000u128)],
+                    reward_claimed: vec![Uint128::from(47500u128)]
                 },
             );
 
@@ -3642,8 +3856,8 @@ mod tests {
                 staked,
                 StakerRewardAssetInfo {
                     keys: vec![],
-                    reward_debt: Uint128::from(257500u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(257500u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -3658,13 +3872,13 @@ mod tests {
             // assert_eq!(term_reward_rates, vec![]);
 
             // get staker total pending reward
-            let total_pending_reward: Uint128 = app
+            let total_pending_reward: Vec<AssetToken> = app
                 .wrap()
                 .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
                 .unwrap();
 
             // USER_1 = 140000, USER_2 = 257500
-            assert_eq!(total_pending_reward, Uint128::from(397500u128));
+            assert_eq!(total_pending_reward[0].amount, Uint128::from(397500u128));
 
             // withdraw remaining reward msg = 1000000 - 47500 - 397500 = 555000
             let withdraw_reward_msg = CampaignExecuteMsg::WithdrawReward {};
@@ -3724,26 +3938,34 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info,
                         amount: Uint128::from(397500u128),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::from_ratio(10000u128, 1u128),
+                        total_reward: Uint128::from(1000000u128),
+                        total_reward_claimed: Uint128::from(47500u128),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
-                    total_reward_claimed: Uint128::from(47500u128),
-                    total_reward: Uint128::from(1000000u128),
-                    reward_per_second: Uint128::from(10000u128),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
         }
@@ -3991,15 +4213,17 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 4,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![LockupTerm {
                         value: 30,
                         percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
                     }],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -4028,10 +4252,14 @@ mod tests {
                 FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }
             );
 
@@ -4053,21 +4281,27 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info.clone(),
                         amount: Uint128::zero(),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        reward_per_second: Decimal256::zero(),
+                        total_reward: Uint128::zero(),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![LockupTerm {
                         value: 30,
                         percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
                     },],
-
-                    total_reward_claimed: Uint128::zero(),
-                    total_reward: Uint128::zero(),
-                    reward_per_second: Uint128::zero(),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -4089,10 +4323,14 @@ mod tests {
                 vec![FactoryCampaign {
                     owner: Addr::unchecked(ADMIN.to_string()),
                     campaign_addr: Addr::unchecked("contract3"),
-                    reward_token: TokenInfo::Token {
+                    reward_tokens: vec![TokenInfo::Token {
                         contract_addr: token_contract.to_string()
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract)
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 4,
                 }]
             );
 
@@ -4115,7 +4353,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -4136,7 +4379,7 @@ mod tests {
 
             assert_eq!(
                 Uint128::from(MOCK_1000_TOKEN_AMOUNT),
-                campaign_info.reward_token.amount
+                campaign_info.reward_tokens[0].amount
             );
 
             // query balance of ADMIN in cw20 base token contract
@@ -4177,6 +4420,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 30,
                 },
@@ -4203,6 +4447,7 @@ mod tests {
             // stake nft token_id 2
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["2".to_string()],
                     lockup_term: 30,
                 },
@@ -4238,11 +4483,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(100000u128),
+                    pending_reward: vec![Uint128::from(100000u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_1,
@@ -4270,11 +4518,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(0u128),
+                    pending_reward: vec![Uint128::from(0u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -4307,14 +4558,15 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
             // stake nft token_id 6
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["6".to_string()],
                     lockup_term: 30,
                 },
@@ -4349,8 +4601,8 @@ mod tests {
                         key: 3,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4380,11 +4632,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(166666u128),
+                    pending_reward: vec![Uint128::from(166666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -4412,11 +4667,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(66666u128),
+                    pending_reward: vec![Uint128::from(66666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_2,
@@ -4444,11 +4702,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(66666u128),
+                    pending_reward: vec![Uint128::from(66666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: false,
                     start_time: start_time_6,
@@ -4481,8 +4742,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4504,8 +4765,8 @@ mod tests {
                         key: 3,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4540,8 +4801,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4563,8 +4824,8 @@ mod tests {
                         key: 3,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4587,11 +4848,14 @@ mod tests {
                 NftInfo {
                     key: 1,
                     token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(166666u128),
+                    pending_reward: vec![Uint128::from(166666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_1,
@@ -4619,11 +4883,14 @@ mod tests {
                 NftInfo {
                     key: 2,
                     token_id: "2".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_1.to_string()),
-                    pending_reward: Uint128::from(116666u128),
+                    pending_reward: vec![Uint128::from(116666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_2,
@@ -4651,11 +4918,14 @@ mod tests {
                 NftInfo {
                     key: 3,
                     token_id: "6".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
                     owner: Addr::unchecked(USER_2.to_string()),
-                    pending_reward: Uint128::from(116666u128),
+                    pending_reward: vec![Uint128::from(116666u128)],
                     lockup_term: LockupTerm {
                         value: 30,
-                        percent: Uint128::from(100u128)
+                        percent: Uint128::from(100u128),
+                        early_unstake_penalty: None,
                     },
                     is_end_reward: true,
                     start_time: start_time_6,
@@ -4666,7 +4936,13 @@ mod tests {
 
             // USER_1 claim reward msg
             let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
-                amount: Uint128::from(283332u128),
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(283332u128),
+                }]),
+                recipient: None,
             };
 
             // Execute claim reward
@@ -4703,8 +4979,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(283332u128),
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(283332u128)],
                 },
             );
 
@@ -4718,6 +4994,7 @@ mod tests {
             // stake nft token_id 7
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["7".to_string()],
                     lockup_term: 30,
                 },
@@ -4744,6 +5021,7 @@ mod tests {
             // stake nft token_id 11
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["11".to_string()],
                     lockup_term: 30,
                 },
@@ -4784,8 +5062,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4820,8 +5098,8 @@ mod tests {
                             lockup_term: 30
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4843,8 +5121,8 @@ mod tests {
                         key: 5,
                         lockup_term: 30
                     }],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4958,8 +5236,8 @@ mod tests {
                 staked,
                 StakerRewardAssetInfo {
                     keys: vec![],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::from(283332u128),
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::from(283332u128)],
                 },
             );
 
@@ -4978,8 +5256,8 @@ mod tests {
                 staked,
                 StakerRewardAssetInfo {
                     keys: vec![],
-                    reward_debt: Uint128::from(266666u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(266666u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -4998,21 +5276,21 @@ mod tests {
                 staked,
                 StakerRewardAssetInfo {
                     keys: vec![],
-                    reward_debt: Uint128::from(50000u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(50000u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
             // get staker total pending reward
-            let total_pending_reward: Uint128 = app
+            let total_pending_reward: Vec<AssetToken> = app
                 .wrap()
                 .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
                 .unwrap();
 
             // USER_2 = 266666, USER_3 = 50000
-            assert_eq!(total_pending_reward, Uint128::from(316668u128));
+            assert_eq!(total_pending_reward[0].amount, Uint128::from(316666u128));
 
-            // withdraw remaining reward msg = 1000000 - 283332 - 316668 = 400000
+            // withdraw remaining reward msg = 1000000 - 283332 - 316666 = 400002, rounding dust included
             let withdraw_reward_msg = CampaignExecuteMsg::WithdrawReward {};
 
             // Execute withdraw remaining reward
@@ -5043,20 +5321,27 @@ mod tests {
                     campaign_image: "campaign name".to_string(),
                     campaign_description: "campaign name".to_string(),
                     limit_per_staker: 4,
-                    reward_token: AssetToken {
+                    reward_tokens: vec![RewardToken {
                         info: token_info,
-                        amount: Uint128::from(316668u128),
-                    },
-                    allowed_collection: Addr::unchecked(collection_contract.clone()),
+                        amount: Uint128::from(316666u128),
+                        reward_per_second: Decimal256::from_ratio(10000u128, 1u128),
+                        total_reward: Uint128::from(1000000u128),
+                        total_reward_claimed: Uint128::from(283332u128),
+                        total_penalty: Uint128::zero(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.to_string(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![LockupTerm {
                         value: 30,
                         percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
                     },],
-                    total_reward_claimed: Uint128::from(283332u128),
-                    total_reward: Uint128::from(1000000u128),
-                    reward_per_second: Uint128::from(10000u128),
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
+                    trait_multipliers: vec![],
+                    cancelled: false,
                 }
             );
 
@@ -5072,7 +5357,7 @@ mod tests {
                 .unwrap();
 
             // It should be 555000 token as withdraw happened
-            assert_eq!(balance.balance, Uint128::from(400000u128));
+            assert_eq!(balance.balance, Uint128::from(400002u128));
 
             // query balance of campaign contract in cw20 base token contract
             let balance: BalanceResponse = app
@@ -5085,11 +5370,11 @@ mod tests {
                 )
                 .unwrap();
 
-            assert_eq!(balance.balance, Uint128::from(316668u128));
+            assert_eq!(balance.balance, Uint128::from(316666u128));
         }
 
         //         -------------- wrong operation ------------------
-        // create campaign with token is native token
+        // create campaign with empty native token denom
         // create campaign with campaign_name.length > 100
         // create campaign with campaign_image, campaign_description > 500
         // create campaign with start_time > end_time
@@ -5265,21 +5550,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5372,23 +5660,26 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 100,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: TokenInfo::NativeToken {
-                            denom: "AURA".to_string(),
-                        },
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![TokenInfo::NativeToken {
+                        denom: "".to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5399,7 +5690,7 @@ mod tests {
                 &create_campaign_msg,
                 &[],
             );
-            // wrong with token is native token
+            // wrong with empty native denom
             assert!(response_create_campaign.is_err());
 
             // create campaign contract by factory contract
@@ -5412,21 +5703,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 100,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(80u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5450,21 +5744,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5488,21 +5785,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5526,21 +5826,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5564,21 +5867,24 @@ mod tests {
                     start_time: current_block_time + 100,
                     end_time: current_block_time + 10,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5602,21 +5908,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 94608020,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info.clone(),
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5640,21 +5949,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 110,
                     limit_per_staker: 4,
-                    reward_token_info: AssetToken {
-                        info: token_info,
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -5703,7 +6015,12 @@ mod tests {
 
             // add reward token with USER_1
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -5727,6 +6044,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 10,
                 },
@@ -5746,7 +6064,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -5762,6 +6085,7 @@ mod tests {
             // stake nft
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec![
                         "1".to_string(),
                         "2".to_string(),
@@ -5786,7 +6110,13 @@ mod tests {
 
             // USER_1 claim reward msg
             let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
-                amount: Uint128::from(20000u128),
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(20000u128),
+                }]),
+                recipient: None,
             };
 
             // Execute claim reward
@@ -5803,6 +6133,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 10,
                 },
@@ -5823,6 +6154,7 @@ mod tests {
             // stake nft
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["2".to_string()],
                     lockup_term: 20,
                 },
@@ -5842,6 +6174,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string(), "2".to_string()],
                     lockup_term: 10,
                 },
@@ -5866,7 +6199,7 @@ mod tests {
             });
 
             // reset pool
-            let reset_pool_msg = CampaignExecuteMsg::ResetPool {};
+            let reset_pool_msg = CampaignExecuteMsg::ResetPool { limit: None };
 
             // Execute reset pool
             let response = app.execute_contract(
@@ -5882,6 +6215,7 @@ mod tests {
             // stake nft token_id 3,4
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["3".to_string(), "4".to_string()],
                     lockup_term: 10,
                 },
@@ -5930,14 +6264,20 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
             // USER_1 claim reward msg
             let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
-                amount: Uint128::from(200000u128),
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(200000u128),
+                }]),
+                recipient: None,
             };
 
             // Execute claim reward
@@ -5990,8 +6330,8 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(0u128),
-                    reward_claimed: Uint128::zero()
+                    reward_debt: vec![Uint128::from(0u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -6082,8 +6422,9 @@ mod tests {
                             lockup_term: 10
                         }
                     ],
-                    reward_debt: Uint128::from(22500u128), // reward in nft 1 transferred
-                    reward_claimed: Uint128::zero()
+                    // reward in nft 1 transferred, reward added at s20 -> reward_per_second = 1000.000 / 90(s)
+                    reward_debt: vec![Uint128::from(12500u128)],
+                    reward_claimed: vec![Uint128::zero()]
                 },
             );
 
@@ -6157,28 +6498,11 @@ mod tests {
         }
 
         // -------------- utils test function ------------------
-        // calc reward in time
         // overflow when add reward
         // overflow when sub reward
         #[test]
         fn utils_test_function() {
-            let start_time: u64 = 10;
-            let end_time: u64 = 20;
-            let reward_per_second: Uint128 = Uint128::from(10u128);
-            let percent: Uint128 = Uint128::from(70u128);
-            let nft_count: u128 = 1;
-
-            // check response calc_reward_in_time
-            let response =
-                calc_reward_in_time(start_time, end_time, reward_per_second, percent, nft_count);
-            assert!(response.is_ok());
-
-            let calc_reward = response.unwrap();
-            assert_eq!(calc_reward, Uint128::from(70u128));
-
-            // check response calc_reward_in_time error
-            let response = calc_reward_in_time(start_time, end_time, reward_per_second, percent, 0);
-            assert!(response.is_err());
+            let calc_reward = Uint128::from(70u128);
 
             // add_reward
             let response = add_reward(Uint128::zero(), calc_reward);
@@ -6201,6 +6525,66 @@ mod tests {
             assert!(response.is_err());
         }
 
+        // -------------- utils checked arithmetic ------------------
+        // overflow when accrue reward of lockup term, with lockup term in error
+        // underflow when checkpoint of nft is after reward per weight, with nft key in error
+        #[test]
+        fn utils_test_checked_arithmetic() {
+            let lockup_term = LockupTerm {
+                value: 10,
+                percent: Uint128::new(100u128),
+                early_unstake_penalty: None,
+            };
+            let mut index = TermRewardIndex {
+                total_weight: 1,
+                last_update: 0,
+                reward_per_weight: vec![Decimal256::zero()],
+                total_reward: vec![Decimal256::zero()],
+                total_dust: vec![Decimal256::zero()],
+            };
+
+            let response = accrue_reward(&mut index, 10, &lockup_term, &[Decimal256::MAX]);
+            assert!(matches!(
+                response,
+                Err(ContractError::Overflow {
+                    term: Some(10),
+                    key: None,
+                    ..
+                })
+            ));
+
+            let mut nft_info = NftInfo {
+                key: 1,
+                token_id: "1".to_string(),
+                collection_addr: Addr::unchecked("collection"),
+                weight: 100,
+                owner: Addr::unchecked(USER_1.to_string()),
+                pending_reward: vec![Uint128::zero()],
+                lockup_term: lockup_term.clone(),
+                is_end_reward: false,
+                start_time: 0,
+                time_calc: 0,
+                end_time: 10,
+            };
+
+            let response = calculate_reward(
+                &mut nft_info,
+                &mut [Decimal256::one()],
+                &mut [Decimal256::zero()],
+                &[Decimal256::zero()],
+                5,
+                100,
+            );
+            assert!(matches!(
+                response,
+                Err(ContractError::Underflow {
+                    term: Some(10),
+                    key: Some(1),
+                    ..
+                })
+            ));
+        }
+
         #[test]
         fn wrong_operation_add_reward() {
             // get integration test app and contracts
@@ -6362,21 +6746,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 100,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info,
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: collection_contract.clone(),
+                    reward_tokens: vec![token_info],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -6409,7 +6796,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -6422,16 +6814,21 @@ mod tests {
 
             assert!(response.is_ok());
 
-            // increase 20 second to make active campaign
+            // increase 100 second to end campaign
             app.set_block(BlockInfo {
-                time: app.block_info().time.plus_seconds(20),
-                height: app.block_info().height + 20,
+                time: app.block_info().time.plus_seconds(100),
+                height: app.block_info().height + 100,
                 chain_id: app.block_info().chain_id,
             });
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -6547,21 +6944,24 @@ mod tests {
                     start_time: current_block_time + 10,
                     end_time: current_block_time + 100,
                     limit_per_staker: 2,
-                    reward_token_info: AssetToken {
-                        info: token_info,
-                        amount: Uint128::zero(),
-                    },
-                    allowed_collection: token_contract.clone(), // fail
+                    reward_tokens: vec![token_info],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: token_contract.clone(), // fail
+                        weight: 1,
+                    }],
                     lockup_term: vec![
                         LockupTerm {
                             value: 10,
                             percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
                         },
                         LockupTerm {
                             value: 30,
                             percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
                         },
                     ],
+                    trait_multipliers: vec![],
                 },
             };
 
@@ -6594,7 +6994,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -6616,7 +7021,12 @@ mod tests {
 
             // add reward token
             let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
-                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                reward_token: AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
             };
 
             // Execute add reward balance
@@ -6632,6 +7042,7 @@ mod tests {
             // stake nft token_id 1
             let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
                 stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
                     token_ids: vec!["1".to_string()],
                     lockup_term: 10,
                 },
@@ -6647,5 +7058,7757 @@ mod tests {
 
             assert!(response.is_err());
         }
+
+        //         -------------- proper operation with native token ------------------
+        // - ADMIN create campaign with native reward token ueaura
+        // - add reward without funds, with wrong amount -> error
+        // - add 1000.000 ueaura reward -> reward_per_second = 10.000 ueaura
+        // - increase 20s, stake nft token_id 1 with lockup_term = 10s, percent = 30% by USER_1
+        // - increase 10s -> token_id 1 pending_reward = 10(s) * 10.000 * 30 / 100 / 1 = 30.000
+        // - USER_1 claim 30.000 ueaura
+        // - increase 100s -> ended campaign, ADMIN withdraw remaining 970.000 ueaura
+        #[test]
+        fn proper_operation_with_native_token() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
+                extension: Some(Metadata {
+                    description: Some("Spaceship with Warp Drive".into()),
+                    name: Some("Starship USS Enterprise".to_string()),
+                    ..Metadata::default()
+                }),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // Approve nft to campaign contract
+            let approve_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::ApproveAll {
+                operator: "contract3".to_string(),
+                expires: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &approve_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // native token info
+            let token_info = TokenInfo::NativeToken {
+                denom: NATIVE_DENOM.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
+                reward_token: AssetToken {
+                    info: token_info.clone(),
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                },
+            };
+
+            // add reward without funds -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &add_reward_balance_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // add reward with funds not equal to amount -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &add_reward_balance_msg,
+                &coins(MOCK_1000_TOKEN_AMOUNT - 1, NATIVE_DENOM),
+            );
+            assert!(response.is_err());
+
+            // add reward with exact funds
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &add_reward_balance_msg,
+                &coins(MOCK_1000_TOKEN_AMOUNT, NATIVE_DENOM),
+            );
+            assert!(response.is_ok());
+
+            // check reward token in campaign
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(campaign_info.reward_tokens[0].info, token_info);
+            assert_eq!(
+                campaign_info.reward_tokens[0].amount,
+                Uint128::from(MOCK_1000_TOKEN_AMOUNT)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(10_000u128, 1u128)
+            );
+
+            // balance of campaign contract
+            let balance = app.wrap().query_balance("contract3", NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::from(MOCK_1000_TOKEN_AMOUNT));
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // stake nft token_id 1
+            let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
+                stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
+                    token_ids: vec!["1".to_string()],
+                    lockup_term: 10,
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &stake_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // claim reward
+            let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
+                amounts: Some(vec![AssetToken {
+                    info: token_info.clone(),
+                    amount: Uint128::from(30_000u128),
+                }]),
+                recipient: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &claim_reward_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 received 30.000 ueaura
+            let balance = app.wrap().query_balance(USER_1, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::from(30_000u128));
+
+            // increase 100 second to end campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(100),
+                height: app.block_info().height + 100,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // withdraw remaining reward
+            let withdraw_reward_msg = CampaignExecuteMsg::WithdrawReward {};
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &withdraw_reward_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN received 970.000 ueaura
+            let balance = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+            assert_eq!(
+                balance.amount,
+                Uint128::from(NATIVE_BALANCE - MOCK_1000_TOKEN_AMOUNT + 970_000u128)
+            );
+
+            // campaign contract has no reward left
+            let balance = app.wrap().query_balance("contract3", NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+        }
+
+        //         -------------- multiple reward tokens ------------------
+        // - ADMIN create campaign with cw20 and native reward tokens
+        // - ADMIN add 1000.000 cw20 token -> reward_per_second = 10.000 token
+        // - ADMIN add 500.000 ueaura -> reward_per_second = 5.000 ueaura
+        // - increase 20s, stake nft token_id 1 with lockup_term = 10s, percent = 30% by USER_1
+        // - increase 10s -> token_id 1 pending_reward:
+        // 	- 10(s) * 10.000 * 30 / 100 / 1 = 30.000 token
+        // 	- 10(s) * 5.000 * 30 / 100 / 1 = 15.000 ueaura
+        // - USER_1 claim all reward -> receive 30.000 token and 15.000 ueaura
+        #[test]
+        fn proper_operation_with_multiple_reward_tokens() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
+                extension: Some(Metadata {
+                    description: Some("Spaceship with Warp Drive".into()),
+                    name: Some("Starship USS Enterprise".to_string()),
+                    ..Metadata::default()
+                }),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // Approve nft to campaign contract
+            let approve_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::ApproveAll {
+                operator: "contract3".to_string(),
+                expires: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &approve_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // cw20 and native token info
+            let token_info = TokenInfo::Token {
+                contract_addr: token_contract.to_string(),
+            };
+            let native_token_info = TokenInfo::NativeToken {
+                denom: NATIVE_DENOM.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign = CreateCampaign {
+                owner: ADMIN.to_string(),
+                campaign_name: "campaign name".to_string(),
+                campaign_image: "campaign name".to_string(),
+                campaign_description: "campaign name".to_string(),
+                start_time: current_block_time + 10,
+                end_time: current_block_time + 110,
+                limit_per_staker: 4,
+                reward_tokens: vec![token_info.clone(), native_token_info.clone()],
+                allowed_collections: vec![AllowedCollection {
+                    collection_addr: collection_contract.clone(),
+                    weight: 1,
+                }],
+                lockup_term: vec![
+                    LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(30u128),
+                        early_unstake_penalty: None,
+                    },
+                    LockupTerm {
+                        value: 30,
+                        percent: Uint128::new(70u128),
+                        early_unstake_penalty: None,
+                    },
+                ],
+                trait_multipliers: vec![],
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::CreateCampaign {
+                    create_campaign: create_campaign.clone(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // duplicated reward tokens -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::CreateCampaign {
+                    create_campaign: CreateCampaign {
+                        reward_tokens: vec![token_info.clone(), token_info.clone()],
+                        ..create_campaign
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN add cw20 reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN add native reward token
+            let add_reward_balance_msg = CampaignExecuteMsg::AddRewardToken {
+                reward_token: AssetToken {
+                    info: native_token_info.clone(),
+                    amount: Uint128::from(500_000u128),
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &add_reward_balance_msg,
+                &coins(500_000u128, NATIVE_DENOM),
+            );
+            assert!(response.is_ok());
+
+            // check reward tokens in campaign
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens,
+                vec![
+                    RewardToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                        reward_per_second: Decimal256::from_ratio(10_000u128, 1u128),
+                        total_reward: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    },
+                    RewardToken {
+                        info: native_token_info.clone(),
+                        amount: Uint128::from(500_000u128),
+                        reward_per_second: Decimal256::from_ratio(5_000u128, 1u128),
+                        total_reward: Uint128::from(500_000u128),
+                        total_reward_claimed: Uint128::zero(),
+                        total_penalty: Uint128::zero(),
+                    },
+                ]
+            );
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // stake nft token_id 1
+            let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
+                stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
+                    token_ids: vec!["1".to_string()],
+                    lockup_term: 10,
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &stake_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // get total pending reward
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(
+                total_pending_reward,
+                vec![
+                    AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(30_000u128),
+                    },
+                    AssetToken {
+                        info: native_token_info.clone(),
+                        amount: Uint128::from(15_000u128),
+                    },
+                ]
+            );
+
+            // claim reward token which is not in campaign -> error
+            let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
+                amounts: Some(vec![AssetToken {
+                    info: TokenInfo::NativeToken {
+                        denom: "uatom".to_string(),
+                    },
+                    amount: Uint128::from(1u128),
+                }]),
+                recipient: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &claim_reward_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // claim all reward
+            let claim_reward_msg = CampaignExecuteMsg::ClaimReward {
+                amounts: None,
+                recipient: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &claim_reward_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 received 30.000 token and 15.000 ueaura
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: USER_1.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::from(30_000u128));
+
+            let balance = app.wrap().query_balance(USER_1, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::from(15_000u128));
+
+            // staker info
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::zero(); 2]);
+            assert_eq!(
+                staker_info.reward_claimed,
+                vec![Uint128::from(30_000u128), Uint128::from(15_000u128)]
+            );
+        }
+
+        //         -------------- multiple allowed collections ------------------
+        // - ADMIN create campaign with collection 1 (weight 1) and collection 2 (weight 3)
+        // - ADMIN add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - increase 20s
+        // 	- USER_1 stake nft token_id 1 of collection 1 with lockup_term = 10s, percent = 30%
+        // 	- USER_2 send nft token_id 1 of collection 2 with lockup_term = 10s, percent = 30%
+        // - increase 10s -> total weight = 4
+        // 	- collection 1 token_id 1 pending_reward = 10(s) * 10.000 * 30 / 100 * 1 / 4 = 7.500
+        // 	- collection 2 token_id 1 pending_reward = 10(s) * 10.000 * 30 / 100 * 3 / 4 = 22.500
+        // - USER_1, USER_2 unstake nft -> nft is returned to its collection
+        #[test]
+        fn proper_operation_with_multiple_collections() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // instantiate second collection contract
+            let second_collection_contract = app
+                .instantiate_contract(
+                    contracts[2].contract_code_id,
+                    Addr::unchecked(ADMIN),
+                    &Cw721InstantiateMsg {
+                        name: "Second Collection".to_string(),
+                        symbol: "SC".to_string(),
+                        minter: ADMIN.to_string(),
+                    },
+                    &[],
+                    "second collection",
+                    None,
+                )
+                .unwrap()
+                .to_string();
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 of each collection to USER_1 and USER_2
+            for (collection, owner) in [
+                (collection_contract.clone(), USER_1),
+                (second_collection_contract.clone(), USER_2),
+            ] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: "1".to_string(),
+                    owner: owner.to_string(),
+                    token_uri: Some(
+                        "https://starships.example.com/Starship/Enterprise.json".into(),
+                    ),
+                    extension: Some(Metadata {
+                        description: Some("Spaceship with Warp Drive".into()),
+                        name: Some("Starship USS Enterprise".to_string()),
+                        ..Metadata::default()
+                    }),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // Approve nft of collection 1 to campaign contract
+            let approve_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::ApproveAll {
+                operator: "contract4".to_string(),
+                expires: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &approve_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![
+                        AllowedCollection {
+                            collection_addr: collection_contract.clone(),
+                            weight: 1,
+                        },
+                        AllowedCollection {
+                            collection_addr: second_collection_contract.clone(),
+                            weight: 3,
+                        },
+                    ],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract4".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // stake nft of collection which is not allowed -> error
+            let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
+                stake_info: NftStake {
+                    collection_addr: token_contract.to_string(),
+                    token_ids: vec!["1".to_string()],
+                    lockup_term: 10,
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract4"),
+                &stake_nft_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // USER_1 stake nft token_id 1 of collection 1
+            let stake_nft_msg = CampaignExecuteMsg::StakeNfts {
+                stake_info: NftStake {
+                    collection_addr: collection_contract.to_string(),
+                    token_ids: vec!["1".to_string()],
+                    lockup_term: 10,
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract4"),
+                &stake_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_2 send nft token_id 1 of collection 2 to campaign
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract4".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked(second_collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // total weight of lockup term 10s = 1 + 3
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract4",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+
+            assert_eq!(term_reward_index.total_weight, 400);
+            assert_eq!(term_reward_index.last_update, current_block_time + 20);
+
+            // increase 10 second to end lockup term
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // nft of collection 2 is stored with its collection and weight
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract4",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 2,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                nft_info.collection_addr,
+                Addr::unchecked(second_collection_contract.clone())
+            );
+            assert_eq!(nft_info.weight, 300);
+            assert_eq!(nft_info.pending_reward, vec![Uint128::from(22_500u128)]);
+
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract4",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.weight, 100);
+            assert_eq!(nft_info.pending_reward, vec![Uint128::from(7_500u128)]);
+
+            // USER_1 and USER_2 unstake nft
+            for (key, owner) in [(1, USER_1), (2, USER_2)] {
+                let un_stake_nft_msg = CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key,
+                        lockup_term: 10,
+                    },
+                    token_id: "1".to_string(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked("contract4"),
+                    &un_stake_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // nft is returned from its collection
+            for (collection, owner) in [
+                (collection_contract.clone(), USER_1),
+                (second_collection_contract.clone(), USER_2),
+            ] {
+                let owner_response: OwnerOfResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        collection,
+                        &Cw721QueryMsg::OwnerOf {
+                            token_id: "1".to_string(),
+                            include_expired: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(owner_response.owner, owner.to_string());
+            }
+        }
+
+        //         -------------- add reward by cw20 send ------------------
+        // - ADMIN create campaign with cw20 reward token
+        // - USER_1 send reward token to campaign -> error, USER_1 is not owner
+        // - ADMIN send 1000.000 reward token to campaign -> reward_per_second = 10.000 token
+        #[test]
+        fn proper_operation_add_reward_by_send() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN and USER_1
+            for recipient in [ADMIN, USER_1] {
+                let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                    recipient: recipient.to_string(),
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(token_contract.clone()),
+                    &mint_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // token info
+            let token_info = TokenInfo::Token {
+                contract_addr: token_contract.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // send reward token to campaign with add reward hook
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            // USER_1 is not owner of campaign
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN send reward token without allowance
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // check reward token in campaign
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(campaign_info.reward_tokens[0].info, token_info);
+            assert_eq!(
+                campaign_info.reward_tokens[0].amount,
+                Uint128::from(MOCK_1000_TOKEN_AMOUNT)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(10_000u128, 1u128)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_reward,
+                Uint128::from(MOCK_1000_TOKEN_AMOUNT)
+            );
+
+            // query balance of campaign contract in cw20 base token contract
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: "contract3".to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::from(MOCK_1000_TOKEN_AMOUNT));
+
+            // Receive must be sent by the reward token contract
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::Receive(Cw20ReceiveMsg {
+                    sender: ADMIN.to_string(),
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                    msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+                }),
+                &[],
+            );
+            assert!(response.is_err());
+        }
+
+        //         -------------- stake nft by cw721 send ------------------
+        // - ADMIN create campaign and add 1000.000 reward token
+        // - increase 20s to make active campaign
+        // - USER_1 send nft token_id 1 with invalid lockup_term -> error
+        // - USER_1 call ReceiveNft directly -> error, sender is not allowed collection
+        // - USER_1 send nft token_id 1 with lockup_term = 10s -> staked
+        // - increase 10s, USER_1 un_stake nft token_id 1 -> token_id 1 is returned to USER_1
+        #[test]
+        fn proper_operation_stake_nft_by_send() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
+                extension: Some(Metadata {
+                    description: Some("Spaceship with Warp Drive".into()),
+                    name: Some("Starship USS Enterprise".to_string()),
+                    ..Metadata::default()
+                }),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // send nft with invalid lockup term
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 20 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ReceiveNft must be sent by allowed collection
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+                    sender: USER_1.to_string(),
+                    token_id: "1".to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                }),
+                &[],
+            );
+            assert!(response.is_err());
+
+            // send nft token_id 1 with lockup term 10s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+            let start_time_1 = app.block_info().time.seconds();
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // nft info of token_id 1
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+
+            assert_eq!(
+                nft_info,
+                NftInfo {
+                    key: 1,
+                    token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked(collection_contract.clone()),
+                    weight: 100,
+                    owner: Addr::unchecked(USER_1.to_string()),
+                    pending_reward: vec![Uint128::zero()],
+                    lockup_term: LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(30u128),
+                        early_unstake_penalty: None,
+                    },
+                    is_end_reward: false,
+                    start_time: start_time_1,
+                    time_calc: start_time_1,
+                    end_time: start_time_1 + 10,
+                }
+            );
+
+            // term reward rates are updated as StakeNfts
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+
+            assert_eq!(term_reward_index.total_weight, 100);
+            assert_eq!(term_reward_index.last_update, start_time_1);
+
+            // token_id 1 is owned by campaign
+            let owner: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection_contract.clone(),
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, "contract3".to_string());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 un stake nft token_id 1
+            let un_stake_nft_msg = CampaignExecuteMsg::UnStakeNft {
+                unstake_info: NftKey {
+                    key: 1,
+                    lockup_term: 10,
+                },
+                token_id: "1".to_string(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &un_stake_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // token_id 1 is returned to USER_1
+            let owner: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection_contract.clone(),
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, USER_1.to_string());
+        }
+
+        //         -------------- migrate campaign ------------------
+        // - ADMIN create campaign contract by factory contract, factory is admin of campaign
+        // - USER_1 migrate campaign -> error, USER_1 is not admin of campaign
+        // - factory migrate campaign -> campaign info is unchanged
+        #[test]
+        fn proper_operation_migrate_campaign() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+
+            // query campaign code id in factory contract
+            let config: ConfigResponse = app
+                .wrap()
+                .query_wasm_smart(factory_contract.clone(), &crate::msg::QueryMsg::Config {})
+                .unwrap();
+
+            // USER_1 is not admin of campaign
+            let response = app.migrate_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignMigrateMsg {},
+                config.campaign_code_id,
+            );
+            assert!(response.is_err());
+
+            // factory is admin of campaign
+            let response = app.migrate_contract(
+                Addr::unchecked(factory_contract.clone()),
+                Addr::unchecked("contract3"),
+                &CampaignMigrateMsg {},
+                config.campaign_code_id,
+            );
+            assert!(response.is_ok());
+
+            // campaign info is unchanged
+            let migrated_campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(migrated_campaign_info, campaign_info);
+        }
+
+        //         -------------- migrate campaigns by factory ------------------
+        // - ADMIN create 2 campaigns by factory contract
+        // - store new campaign code
+        // - USER_1 migrate campaigns -> error, USER_1 is not owner of factory
+        // - ADMIN migrate more than 30 campaigns -> error
+        // - ADMIN migrate first page with limit 1 -> campaign 1 has new code id
+        // - ADMIN migrate campaign 2 by id -> campaign 2 has new code id
+        #[test]
+        fn proper_operation_migrate_campaigns_by_factory() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            // create 2 campaigns
+            for _ in 0..2 {
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(factory_contract.clone()),
+                    &create_campaign_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // store new campaign code
+            let new_code_id = app.store_code(contract_template());
+
+            // migrate first page with limit 1
+            let migrate_campaigns_msg = crate::msg::ExecuteMsg::MigrateCampaigns {
+                new_code_id,
+                campaigns: CampaignSelection::Range {
+                    start_after: None,
+                    limit: Some(1),
+                },
+                msg: to_binary(&CampaignMigrateMsg {}).unwrap(),
+            };
+
+            // USER_1 is not owner of factory
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &migrate_campaigns_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // too many campaigns in one execution
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::MigrateCampaigns {
+                    new_code_id,
+                    campaigns: CampaignSelection::Ids {
+                        campaign_ids: (1..=31).collect(),
+                    },
+                    msg: to_binary(&CampaignMigrateMsg {}).unwrap(),
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &migrate_campaigns_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // only campaign 1 is migrated
+            let campaigns: Vec<FactoryCampaign> = app
+                .wrap()
+                .query_wasm_smart(
+                    Addr::unchecked(factory_contract.clone()),
+                    &QueryMsg::Campaigns {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(campaigns[0].code_id, new_code_id);
+            assert_eq!(campaigns[1].code_id, 4);
+
+            let contract_info = app.contract_data(&campaigns[0].campaign_addr).unwrap();
+            assert_eq!(contract_info.code_id, new_code_id as usize);
+
+            // migrate campaign 2 by id
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::MigrateCampaigns {
+                    new_code_id,
+                    campaigns: CampaignSelection::Ids {
+                        campaign_ids: vec![2],
+                    },
+                    msg: to_binary(&CampaignMigrateMsg {}).unwrap(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let campaign: FactoryCampaign = app
+                .wrap()
+                .query_wasm_smart(
+                    factory_contract.clone(),
+                    &crate::msg::QueryMsg::Campaign { campaign_id: 2 },
+                )
+                .unwrap();
+            assert_eq!(campaign.code_id, new_code_id);
+
+            let contract_info = app.contract_data(&campaign.campaign_addr).unwrap();
+            assert_eq!(contract_info.code_id, new_code_id as usize);
+        }
+
+        //         -------------- trait multipliers ------------------
+        // - ADMIN create collection with on-chain metadata
+        // - mint token_id 1 (rarity: legendary) to USER_1, token_id 2 (rarity: rare) to USER_2
+        // - ADMIN create campaign with collection weight above limit -> error
+        // - ADMIN create campaign with multipliers legendary = 3, rare = 1.5
+        // - add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1, USER_2 stake nfts with lockup_term = 10s, percent = 100%
+        // 	- token_id 1 weight = 300, token_id 2 weight = 150
+        // - increase 10s
+        // 	- token_id 1 pending_reward = 10(s) * 10.000 * 100 / 100 * 300 / 450 = 66.666
+        // 	- token_id 2 pending_reward = 10(s) * 10.000 * 100 / 100 * 150 / 450 = 33.333
+        // - USER_1 update trait multipliers -> error, USER_1 is not owner
+        // - ADMIN update trait multipliers with zero multiplier -> error
+        // - ADMIN update legendary multiplier to u64::MAX, USER_1 restake token_id 1 -> error, weight overflows
+        #[test]
+        fn proper_operation_with_trait_multipliers() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+
+            // collection contract with on-chain metadata
+            let collection_code_id = app.store_code(metadata_collection_contract_template());
+            let collection_contract = app
+                .instantiate_contract(
+                    collection_code_id,
+                    Addr::unchecked(ADMIN),
+                    &Cw721InstantiateMsg {
+                        name: "Metadata Collection".to_string(),
+                        symbol: "MC".to_string(),
+                        minter: ADMIN.to_string(),
+                    },
+                    &[],
+                    "test instantiate contract",
+                    None,
+                )
+                .unwrap()
+                .to_string();
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1 and token_id 2 to USER_2
+            for (token_id, owner, rarity) in [("1", USER_1, "legendary"), ("2", USER_2, "rare")] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: Some(Metadata {
+                        attributes: Some(vec![Trait {
+                            display_type: None,
+                            trait_type: "rarity".to_string(),
+                            value: rarity.to_string(),
+                        }]),
+                        ..Metadata::default()
+                    }),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![
+                        TraitMultiplier {
+                            trait_type: "rarity".to_string(),
+                            value: "legendary".to_string(),
+                            multiplier: Decimal::percent(300),
+                        },
+                        TraitMultiplier {
+                            trait_type: "rarity".to_string(),
+                            value: "rare".to_string(),
+                            multiplier: Decimal::percent(150),
+                        },
+                    ],
+                },
+            };
+
+            // weight of collection must not exceed limit
+            let mut invalid_create_campaign_msg = create_campaign_msg.clone();
+            if let crate::msg::ExecuteMsg::CreateCampaign { create_campaign } =
+                &mut invalid_create_campaign_msg
+            {
+                create_campaign.allowed_collections[0].weight = 10_001;
+            }
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &invalid_create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract4".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1, USER_2 stake token_id 2 with lockup term 10s
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract4".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // simulated nft has weight of traits of token_id
+            // 	- token_id 1: 10(s) * 10.000 * 300 / (450 + 300) = 40.000
+            // 	- no token_id: 10(s) * 10.000 * 100 / (450 + 100) = 18.181
+            for (token_id, reward) in [(Some("1".to_string()), 40_000u128), (None, 18_181u128)] {
+                let simulation: SimulateStakeResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        "contract4",
+                        &CampaignQueryMsg::SimulateStake {
+                            collection_addr: collection_contract.clone(),
+                            token_id,
+                            lockup_term: 10,
+                            count: 1,
+                            at_time: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(simulation.reward_per_nft[0].amount, Uint128::new(reward));
+            }
+
+            // weight of nfts is multiplied by their traits
+            for (key, weight) in [(1, 300), (2, 150)] {
+                let nft_info: NftInfo = app
+                    .wrap()
+                    .query_wasm_smart(
+                        "contract4",
+                        &CampaignQueryMsg::NftInfo {
+                            nft_key: NftKey {
+                                key,
+                                lockup_term: 10,
+                            },
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(nft_info.weight, weight);
+            }
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // reward is split by weight
+            for (key, reward) in [(1, 66_666u128), (2, 33_333u128)] {
+                let nft_info: NftInfo = app
+                    .wrap()
+                    .query_wasm_smart(
+                        "contract4",
+                        &CampaignQueryMsg::NftInfo {
+                            nft_key: NftKey {
+                                key,
+                                lockup_term: 10,
+                            },
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(nft_info.pending_reward, vec![Uint128::new(reward)]);
+            }
+
+            // USER_1 is not owner of campaign
+            let update_msg = CampaignExecuteMsg::UpdateTraitMultipliers {
+                trait_multipliers: vec![],
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract4"),
+                &update_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // multiplier must not be zero
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::UpdateTraitMultipliers {
+                    trait_multipliers: vec![TraitMultiplier {
+                        trait_type: "rarity".to_string(),
+                        value: "common".to_string(),
+                        multiplier: Decimal::zero(),
+                    }],
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &update_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // weight of nft with huge multiplier overflows u64
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::UpdateTraitMultipliers {
+                    trait_multipliers: vec![TraitMultiplier {
+                        trait_type: "rarity".to_string(),
+                        value: "legendary".to_string(),
+                        multiplier: Decimal::from_ratio(u64::MAX, 1u64),
+                    }],
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key: 1,
+                        lockup_term: 10,
+                    },
+                    token_id: "1".to_string(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::SendNft {
+                    contract: "contract4".to_string(),
+                    token_id: "1".to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                },
+                &[],
+            );
+            assert!(response.is_err());
+        }
+
+        //         -------------- early unstake ------------------
+        // - ADMIN create campaign with lockup terms:
+        // 	- 10s, 30%, penalty 50%, penalty is kept in pool
+        // 	- 30s, 70%, penalty 100% decays to the end of lockup term, penalty is sent to USER_3
+        // - add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1 stake token_id 1 with lockup_term = 10s, USER_2 stake token_id 2 with lockup_term = 30s
+        // - increase 4s, USER_1 un stake token_id 1
+        // 	- pending_reward = 4(s) * 10.000 * 30 / 100 = 12.000, penalty = 6.000
+        // 	- USER_1 reward_debt = 6.000
+        // - increase 6s, USER_2 un stake token_id 2
+        // 	- pending_reward = 10(s) * 10.000 * 70 / 100 = 70.000, penalty = 70.000 * 100% * 20 / 30 = 46.666
+        // 	- USER_2 reward_debt = 23.334, USER_3 receive 46.666
+        // - total_pending_reward = 6.000 + 23.334 = 29.334
+        #[test]
+        fn proper_operation_early_unstake_with_penalty() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1 and token_id 2 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            let create_campaign = CreateCampaign {
+                owner: ADMIN.to_string(),
+                campaign_name: "campaign name".to_string(),
+                campaign_image: "campaign name".to_string(),
+                campaign_description: "campaign name".to_string(),
+                start_time: current_block_time + 10,
+                end_time: current_block_time + 110,
+                limit_per_staker: 4,
+                reward_tokens: vec![TokenInfo::Token {
+                    contract_addr: token_contract.to_string(),
+                }],
+                allowed_collections: vec![AllowedCollection {
+                    collection_addr: collection_contract.clone(),
+                    weight: 1,
+                }],
+                lockup_term: vec![
+                    LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(30u128),
+                        early_unstake_penalty: Some(EarlyUnstakePenalty {
+                            percent: Uint128::new(50u128),
+                            decay: false,
+                            recipient: None,
+                        }),
+                    },
+                    LockupTerm {
+                        value: 30,
+                        percent: Uint128::new(70u128),
+                        early_unstake_penalty: Some(EarlyUnstakePenalty {
+                            percent: Uint128::new(100u128),
+                            decay: true,
+                            recipient: Some(USER_3.to_string()),
+                        }),
+                    },
+                ],
+                trait_multipliers: vec![],
+            };
+
+            // penalty percent must be less than or equal 100
+            let mut invalid_create_campaign = create_campaign.clone();
+            invalid_create_campaign.lockup_term[0]
+                .early_unstake_penalty
+                .as_mut()
+                .unwrap()
+                .percent = Uint128::new(101u128);
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::CreateCampaign {
+                    create_campaign: invalid_create_campaign,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // create campaign contract by factory contract
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &crate::msg::ExecuteMsg::CreateCampaign { create_campaign },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+            let start_time = app.block_info().time.seconds();
+
+            // USER_1 stake token_id 1 with lockup term 10s, USER_2 stake token_id 2 with lockup term 30s
+            for (token_id, owner, lockup_term) in [("1", USER_1, 10), ("2", USER_2, 30)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 4 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(4),
+                height: app.block_info().height + 4,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 un stake token_id 1 before end of lockup term
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key: 1,
+                        lockup_term: 10,
+                    },
+                    token_id: "1".to_string(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // weight of token_id 1 is removed from reward rates
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+
+            assert_eq!(term_reward_index.total_weight, 0);
+            assert_eq!(term_reward_index.last_update, start_time + 4);
+
+            // USER_1 receive half of pending reward
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(6_000u128)]);
+
+            // token_id 1 is returned to USER_1
+            let owner: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection_contract.clone(),
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, USER_1.to_string());
+
+            // increase 6 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(6),
+                height: app.block_info().height + 6,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_2 un stake token_id 2 before end of lockup term
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key: 1,
+                        lockup_term: 30,
+                    },
+                    token_id: "2".to_string(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_2.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(23_334u128)]);
+
+            // penalty is sent to USER_3
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: USER_3.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(46_666u128));
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].amount,
+                Uint128::new(MOCK_1000_TOKEN_AMOUNT - 46_666u128)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_penalty,
+                Uint128::new(52_666u128)
+            );
+
+            // total pending reward is reward_debt of USER_1 and USER_2
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(29_334u128));
+        }
+
+        //         -------------- batch unstake ------------------
+        // - USER_1 stake token_id 1, 2 with lockup_term = 10s (30%) and token_id 3 with lockup_term = 30s (70%)
+        // - USER_2 stake token_id 4 with lockup_term = 30s
+        // - increase 12s
+        // - USER_1 un stake nfts with key of USER_2 -> error, USER_1 is not owner
+        // - USER_1 un stake nfts with duplicated keys -> error
+        // - USER_1 un stake token_id 1 and token_id 3 -> token_id 3 is skipped, it is in staking period
+        // 	- token_id 1 pending_reward = 10(s) * 10.000 * 30 / 100 / 2 (nft_count) = 15.000
+        // - increase 30s
+        // - USER_1 un stake all -> token_id 2, token_id 3 are returned
+        // 	- token_id 2 pending_reward = 15.000
+        // 	- token_id 3 pending_reward = 30(s) * 10.000 * 70 / 100 / 2 (nft_count) = 105.000
+        // - USER_1 reward_debt = 15.000 + 15.000 + 105.000 = 135.000
+        #[test]
+        fn proper_operation_unstake_nfts_and_unstake_all() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1, 2, 3 to USER_1 and token_id 4 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_1), ("3", USER_1), ("4", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // stake token_id 1, 2 with lockup term 10s, token_id 3, 4 with lockup term 30s
+            for (token_id, owner, lockup_term) in [
+                ("1", USER_1, 10),
+                ("2", USER_1, 10),
+                ("3", USER_1, 30),
+                ("4", USER_2, 30),
+            ] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 12 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(12),
+                height: app.block_info().height + 12,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // token_id 4 (key 2, lockup term 30s) is owned by USER_2
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNfts {
+                    keys: vec![
+                        NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                        NftKey {
+                            key: 2,
+                            lockup_term: 30,
+                        },
+                    ],
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // more keys than limit_per_staker
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNfts {
+                    keys: (1..=5)
+                        .map(|key| NftKey {
+                            key,
+                            lockup_term: 10,
+                        })
+                        .collect(),
+                },
+                &[],
+            );
+            assert_eq!(
+                response.unwrap_err().root_cause().to_string(),
+                "## Too many nfts, limit is 4 ##"
+            );
+
+            // duplicated keys
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNfts {
+                    keys: vec![
+                        NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                        NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    ],
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // token_id 3 is skipped
+            let response = app
+                .execute_contract(
+                    Addr::unchecked(USER_1.to_string()),
+                    Addr::unchecked("contract3"),
+                    &CampaignExecuteMsg::UnStakeNfts {
+                        keys: vec![
+                            NftKey {
+                                key: 1,
+                                lockup_term: 10,
+                            },
+                            NftKey {
+                                key: 1,
+                                lockup_term: 30,
+                            },
+                        ],
+                    },
+                    &[],
+                )
+                .unwrap();
+            let attributes = &response.custom_attrs(1);
+            assert!(attributes
+                .iter()
+                .any(|attr| attr.key == "unstaked_nfts" && attr.value == "1: 10"));
+            assert!(attributes
+                .iter()
+                .any(|attr| attr.key == "skipped_nfts" && attr.value == "1: 30"));
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(15_000u128)]);
+            assert_eq!(staker_info.keys.len(), 2);
+
+            // increase 30 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(30),
+                height: app.block_info().height + 30,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeAll {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // all nfts are returned to USER_1
+            for token_id in ["1", "2", "3"] {
+                let owner: OwnerOfResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        collection_contract.clone(),
+                        &Cw721QueryMsg::OwnerOf {
+                            token_id: token_id.to_string(),
+                            include_expired: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(owner.owner, USER_1.to_string());
+            }
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(135_000u128)]);
+            assert!(staker_info.keys.is_empty());
+
+            // no nft to unstake
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeAll {},
+                &[],
+            );
+            assert!(response.is_err());
+        }
+
+        //         -------------- change lockup term ------------------
+        // - USER_1 stake token_id 1, USER_2 stake token_id 2 with lockup_term = 10s (30%)
+        // - increase 4s
+        // - USER_2 change lockup term of token_id 1 -> error, USER_2 is not owner
+        // - USER_1 change lockup term of token_id 1 to 30s (70%)
+        // 	- token_id 1 pending_reward = 4(s) * 10.000 * 30 / 100 / 2 (nft_count) = 6.000 -> USER_1 reward_debt = 6.000
+        // 	- token_id 1 has new key 1 in lockup term 30s
+        // - USER_1 change lockup term of token_id 1 to 10s -> error, lockup term is shorter
+        // - USER_1 change lockup term of token_id 1 to 30s -> error, lockup term is not longer
+        // - increase 6s
+        // 	- token_id 1 pending_reward = 6(s) * 10.000 * 70 / 100 = 42.000
+        // 	- token_id 2 pending_reward = 6.000 + 6(s) * 10.000 * 30 / 100 = 24.000
+        #[test]
+        fn proper_operation_change_lockup_term() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1 and token_id 2 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+            let start_time = app.block_info().time.seconds();
+
+            // USER_1 stake token_id 1, USER_2 stake token_id 2 with lockup term 10s
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 4 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(4),
+                height: app.block_info().height + 4,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let change_lockup_term_msg = CampaignExecuteMsg::ChangeLockupTerm {
+                key: NftKey {
+                    key: 1,
+                    lockup_term: 10,
+                },
+                new_term: 30,
+            };
+
+            // USER_2 is not owner of token_id 1
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &change_lockup_term_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &change_lockup_term_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // reward of token_id 1 is settled, token_id 1 has new key
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                staker_info.keys,
+                vec![NftKey {
+                    key: 1,
+                    lockup_term: 30,
+                }]
+            );
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(6_000u128)]);
+
+            // weight of token_id 1 is moved from lockup term 10s to 30s
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+
+            assert_eq!(term_reward_index.total_weight, 100);
+            assert_eq!(term_reward_index.last_update, start_time + 4);
+
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 30 },
+                )
+                .unwrap();
+
+            assert_eq!(term_reward_index.total_weight, 100);
+            assert_eq!(term_reward_index.last_update, start_time + 4);
+
+            // token_id 1 is still owned by campaign
+            let owner: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection_contract.clone(),
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, "contract3".to_string());
+
+            // lockup term 10s is shorter than 30s
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ChangeLockupTerm {
+                    key: NftKey {
+                        key: 1,
+                        lockup_term: 30,
+                    },
+                    new_term: 10,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // lockup term 30s is not longer than 30s
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ChangeLockupTerm {
+                    key: NftKey {
+                        key: 1,
+                        lockup_term: 30,
+                    },
+                    new_term: 30,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // increase 6 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(6),
+                height: app.block_info().height + 6,
+                chain_id: app.block_info().chain_id,
+            });
+
+            for (key, lockup_term, reward) in [(1, 30, 42_000u128), (2, 10, 24_000u128)] {
+                let nft_info: NftInfo = app
+                    .wrap()
+                    .query_wasm_smart(
+                        "contract3",
+                        &CampaignQueryMsg::NftInfo {
+                            nft_key: NftKey { key, lockup_term },
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(nft_info.pending_reward, vec![Uint128::new(reward)]);
+            }
+        }
+
+        //         -------------- top up reward ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - increase 20s, USER_1 stake token_id 1 with lockup_term = 30s (70%)
+        // - increase 10s -> token_id 1 pending_reward = 10(s) * 10.000 * 70 / 100 = 70.000
+        // - ADMIN top up 900.000 reward token
+        // 	- reward_per_second = 10.000 + 900.000 / 80(s) remaining = 21.250
+        // 	- token_id 1 pending_reward is unchanged = 70.000
+        // - increase 10s -> token_id 1 pending_reward = 70.000 + 10(s) * 21.250 * 70 / 100 = 218.750
+        // - increase 80s -> ended campaign, token_id 1 is ended at s50
+        // 	- total_pending_reward = 70.000 + 20(s) * 21.250 * 70 / 100 = 367.500
+        // 	- withdraw_reward = 1900.000 - 367.500 = 1532.500
+        #[test]
+        fn proper_operation_top_up_reward() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 2000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(2 * MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 30s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // top up 900 tokens to active campaign
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(900_000u128),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // reward_per_second is raised for remaining duration
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(21_250u128, 1u128)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_reward,
+                Uint128::new(1_900_000u128)
+            );
+
+            // reward accrued before top up is unchanged
+            let nft_key = NftKey {
+                key: 1,
+                lockup_term: 30,
+            };
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: nft_key.clone(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(70_000u128)]);
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: nft_key.clone(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(218_750u128)]);
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(218_750u128));
+
+            // increase 80 second to end campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(80),
+                height: app.block_info().height + 80,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(367_500u128));
+
+            // withdraw remaining reward
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // campaign keeps total pending reward
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: "contract3".to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(367_500u128));
+
+            // ADMIN token = 100.000 + 1532.500
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: ADMIN.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(1_632_500u128));
+        }
+
+        //         -------------- update end time ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1 stake token_id 1 at s20 with lockup_term = 30s (70%) -> token_id 1 is ended at s50
+        // 	- token_id 1 pending_reward = 30(s) * 10.000 * 70 / 100 = 210.000
+        // - USER_2 stake token_id 2 at s100 with lockup_term = 30s -> token_id 2 is ended by campaign at s110
+        // 	- token_id 2 pending_reward = 10(s) * 10.000 * 70 / 100 = 70.000
+        // - at s120, ADMIN extend end time to s140 and deposit 200.000 reward token
+        // 	- reward is not accrued from s110 to s120
+        // 	- reward_per_second = (1200.000 - 280.000) / 20(s) = 46.000
+        // 	- token_id 2 earns reward again until s130
+        // - at s130, token_id 2 pending_reward = 70.000 + 10(s) * 46.000 * 70 / 100 = 392.000
+        // - at s140, total_pending_reward = 210.000 + 392.000 = 602.000
+        // 	- withdraw_reward = 1200.000 - 602.000 = 598.000
+        #[test]
+        fn proper_operation_update_end_time() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 2000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(2 * MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1 and token_id 2 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 stake token_id 1 at s20, USER_2 stake token_id 2 at s100
+            for (token_id, owner, seconds) in [("1", USER_1, 20), ("2", USER_2, 80)] {
+                app.set_block(BlockInfo {
+                    time: app.block_info().time.plus_seconds(seconds),
+                    height: app.block_info().height + seconds,
+                    chain_id: app.block_info().chain_id,
+                });
+
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 20 second to s120, campaign is ended
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // ADMIN allow campaign to transfer 200 tokens
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: "contract3".to_string(),
+                    amount: Uint128::new(200_000u128),
+                    expires: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let update_end_time_msg = CampaignExecuteMsg::UpdateEndTime {
+                end_time: current_block_time + 140,
+                reward_token: Some(AssetToken {
+                    info: TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    },
+                    amount: Uint128::new(200_000u128),
+                }),
+            };
+
+            // USER_1 is not owner of campaign
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &update_end_time_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // new end time must be in the future
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateEndTime {
+                    end_time: current_block_time + 120,
+                    reward_token: None,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &update_end_time_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(campaign_info.end_time, current_block_time + 140);
+            assert_eq!(
+                campaign_info.reward_tokens[0].amount,
+                Uint128::new(1_200_000u128)
+            );
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(46_000u128, 1u128)
+            );
+
+            // increase 10 second to s130
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            for (key, reward) in [(1, 210_000u128), (2, 392_000u128)] {
+                let nft_info: NftInfo = app
+                    .wrap()
+                    .query_wasm_smart(
+                        "contract3",
+                        &CampaignQueryMsg::NftInfo {
+                            nft_key: NftKey {
+                                key,
+                                lockup_term: 30,
+                            },
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(nft_info.pending_reward, vec![Uint128::new(reward)]);
+            }
+
+            // increase 10 second to s140, campaign is ended
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(602_000u128));
+
+            // withdraw remaining reward
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN token = 800.000 + 598.000
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: ADMIN.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(1_398_000u128));
+        }
+
+        //         -------------- pause and emergency withdraw ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1 stake token_id 1 at s20 with lockup_term = 30s (70%)
+        // - ADMIN pause campaign -> staking and claiming reward are blocked
+        // - ADMIN unpause campaign, emergency withdraw is not allowed yet
+        // - at s30, ADMIN enable emergency mode, USER_1 withdraw token_id 1 before end of lockup term
+        // 	- pending_reward = 10(s) * 10.000 * 70 / 100 = 70.000 is forfeited and kept in pool
+        // - after campaign is ended, ADMIN withdraw all 1000.000 token
+        #[test]
+        fn proper_operation_pause_and_emergency_withdraw() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 and token_id 2 to USER_1
+            for token_id in ["1", "2"] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: USER_1.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // only admin can pause campaign
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetPaused { paused: true },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN pause campaign
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetPaused { paused: true },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // query status
+            let status: StatusResponse = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::Status {})
+                .unwrap();
+            assert_eq!(
+                status,
+                StatusResponse {
+                    paused: true,
+                    emergency: false,
+                }
+            );
+
+            // USER_1 cannot stake token_id 2 while campaign is paused
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "2".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            // USER_1 cannot claim reward while campaign is paused
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN unpause campaign
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetPaused { paused: false },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // emergency withdraw is not allowed out of emergency mode
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::EmergencyWithdraw {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // ADMIN enable emergency mode
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetEmergency { emergency: true },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 withdraw token_id 1 before end of lockup term
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::EmergencyWithdraw {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // token_id 1 is returned to USER_1
+            let owner: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection_contract.clone(),
+                    &Cw721QueryMsg::OwnerOf {
+                        token_id: "1".to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, USER_1.to_string());
+
+            // pending reward of token_id 1 is forfeited
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert!(staker_info.keys.is_empty());
+            assert_eq!(staker_info.reward_debt, vec![Uint128::zero()]);
+
+            // increase 100 second to end campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(100),
+                height: app.block_info().height + 100,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // ADMIN withdraw all reward
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: ADMIN.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::from(MOCK_1000_TOKEN_AMOUNT));
+        }
+
+        //         -------------- cancel campaign ------------------
+        // - ADMIN create 2 campaigns from s10 to s110, add 1000.000 reward token to each campaign
+        // - at s20, USER_1 stake token_id 1 in campaign 1
+        // 	- campaign 1 cannot be cancelled because it has a staker
+        // - USER_1 cannot cancel campaign 2
+        // - ADMIN cancel campaign 2 -> ADMIN is refunded 1000.000 token
+        // 	- every execute of campaign 2 is rejected
+        #[test]
+        fn proper_operation_cancel_campaign() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 2000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(2 * MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create 2 campaigns and add reward token to each campaign
+            for campaign_contract in ["contract3", "contract4"] {
+                let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                    create_campaign: CreateCampaign {
+                        owner: ADMIN.to_string(),
+                        campaign_name: "campaign name".to_string(),
+                        campaign_image: "campaign name".to_string(),
+                        campaign_description: "campaign name".to_string(),
+                        start_time: current_block_time + 10,
+                        end_time: current_block_time + 110,
+                        limit_per_staker: 4,
+                        reward_tokens: vec![TokenInfo::Token {
+                            contract_addr: token_contract.to_string(),
+                        }],
+                        allowed_collections: vec![AllowedCollection {
+                            collection_addr: collection_contract.clone(),
+                            weight: 1,
+                        }],
+                        lockup_term: vec![LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(100u128),
+                            early_unstake_penalty: None,
+                        }],
+                        trait_multipliers: vec![],
+                    },
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(factory_contract.clone()),
+                    &create_campaign_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+
+                let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                    contract: campaign_contract.to_string(),
+                    amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                    msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(token_contract.clone()),
+                    &send_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 in campaign 1
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // campaign 1 has started and has a staker -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::CancelCampaign {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // only owner or admin can cancel campaign
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::CancelCampaign {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN cancel campaign 2
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::CancelCampaign {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN is refunded 1000.000 token
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: ADMIN.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::from(MOCK_1000_TOKEN_AMOUNT));
+
+            // campaign 2 is cancelled with no reward left
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract4", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert!(campaign_info.cancelled);
+            assert_eq!(campaign_info.reward_tokens[0].amount, Uint128::zero());
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::zero()
+            );
+
+            // every execute of cancelled campaign is rejected
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::CancelCampaign {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract4"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_err());
+        }
+
+        //         -------------- update campaign ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - before campaign starts, ADMIN update name, limit_per_staker, lockup_term and start_time = s60
+        // 	- reward_per_second = 1000.000 / 50(s) = 20.000 token
+        // - after campaign starts, ADMIN can only update display metadata
+        #[test]
+        fn proper_operation_update_campaign() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let update_campaign = UpdateCampaignInfo {
+                campaign_name: None,
+                campaign_image: None,
+                campaign_description: None,
+                limit_per_staker: None,
+                lockup_term: None,
+                start_time: None,
+            };
+
+            // only owner can update campaign
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        campaign_name: Some("new campaign name".to_string()),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // campaign name is too long -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        campaign_name: Some("a".repeat(101)),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // percents of lockup terms do not sum to 100 -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        lockup_term: Some(vec![LockupTerm {
+                            value: 20,
+                            percent: Uint128::new(50u128),
+                            early_unstake_penalty: None,
+                        }]),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // lockup term of 0 second -> error
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        lockup_term: Some(vec![LockupTerm {
+                            value: 0,
+                            percent: Uint128::new(100u128),
+                            early_unstake_penalty: None,
+                        }]),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN update campaign before it starts
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        campaign_name: Some("new campaign name".to_string()),
+                        limit_per_staker: Some(2),
+                        lockup_term: Some(vec![LockupTerm {
+                            value: 20,
+                            percent: Uint128::new(100u128),
+                            early_unstake_penalty: None,
+                        }]),
+                        start_time: Some(current_block_time + 60),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // check campaign info
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(campaign_info.campaign_name, "new campaign name".to_string());
+            assert_eq!(campaign_info.limit_per_staker, 2);
+            assert_eq!(campaign_info.start_time, current_block_time + 60);
+            assert_eq!(campaign_info.lockup_term.len(), 1);
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(20_000u128, 1u128)
+            );
+
+            // new lockup term is ready for staking
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 20 },
+                )
+                .unwrap();
+            assert_eq!(term_reward_index.total_weight, 0);
+            assert_eq!(term_reward_index.last_update, current_block_time + 60);
+
+            // increase 70 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(70),
+                height: app.block_info().height + 70,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // schedule cannot be updated after campaign starts
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        limit_per_staker: Some(4),
+                        ..update_campaign.clone()
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            // display metadata can be updated after campaign starts
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        campaign_description: Some("new campaign description".to_string()),
+                        ..update_campaign
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.campaign_description,
+                "new campaign description".to_string()
+            );
+        }
+
+        //         -------------- two-step ownership transfer ------------------
+        // - ADMIN propose USER_1 as new owner of campaign, expires in 10s
+        // 	- USER_2 cannot accept, ADMIN cancel the proposal then USER_1 cannot accept
+        // - ADMIN propose USER_1 again, USER_1 accept after expiration -> error
+        // - ADMIN propose USER_1 without expiration, USER_1 accept -> USER_1 is owner
+        // - ADMIN propose USER_2 as new admin, USER_2 accept -> USER_2 is admin
+        #[test]
+        fn proper_operation_transfer_ownership() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // only owner can propose new owner
+            let propose_owner_msg = CampaignExecuteMsg::ProposeNewOwner {
+                owner: USER_1.to_string(),
+                expires_at: Some(current_block_time + 10),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &propose_owner_msg,
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &propose_owner_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // query pending owner
+            let pending_owner: Option<PendingTransfer> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::PendingOwner {})
+                .unwrap();
+            assert_eq!(
+                pending_owner,
+                Some(PendingTransfer {
+                    new_address: Addr::unchecked(USER_1.to_string()),
+                    expires_at: Some(current_block_time + 10),
+                })
+            );
+
+            // only proposed owner can accept
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptOwnership {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN cancel the proposal
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::CancelOwnershipProposal {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptOwnership {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN propose USER_1 again
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &propose_owner_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 10 second, the proposal is expired
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptOwnership {},
+                &[],
+            );
+            assert!(response.is_err());
+
+            // ADMIN propose USER_1 without expiration
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ProposeNewOwner {
+                    owner: USER_1.to_string(),
+                    expires_at: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptOwnership {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 is owner of campaign, no pending owner left
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(campaign_info.owner, Addr::unchecked(USER_1.to_string()));
+
+            let pending_owner: Option<PendingTransfer> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::PendingOwner {})
+                .unwrap();
+            assert_eq!(pending_owner, None);
+
+            // ADMIN propose USER_2 as new admin
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ProposeNewAdmin {
+                    admin: USER_2.to_string(),
+                    expires_at: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let pending_admin: Option<PendingTransfer> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::PendingAdmin {})
+                .unwrap();
+            assert_eq!(
+                pending_admin,
+                Some(PendingTransfer {
+                    new_address: Addr::unchecked(USER_2.to_string()),
+                    expires_at: None,
+                })
+            );
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AcceptAdmin {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_2 is admin and can pause campaign, ADMIN cannot
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetPaused { paused: true },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetPaused { paused: true },
+                &[],
+            );
+            assert!(response.is_ok());
+        }
+
+        //         -------------- claim reward to recipient ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1 stake token_id 1 at s20 with lockup_term = 10s (100%)
+        // 	- at s30, token_id 1 pending_reward = 10(s) * 10.000 = 100.000
+        // - USER_1 set USER_3 as reward recipient, claim 40.000 -> USER_3 receive 40.000
+        // - USER_1 claim remaining reward to USER_2 -> USER_2 receive 60.000
+        #[test]
+        fn proper_operation_claim_reward_to_recipient() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint nft token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 10 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(10),
+                height: app.block_info().height + 10,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 set USER_3 as reward recipient
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetRewardRecipient {
+                    recipient: Some(USER_3.to_string()),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let reward_recipient: Option<Addr> = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::RewardRecipient {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(reward_recipient, Some(Addr::unchecked(USER_3.to_string())));
+
+            // USER_1 claim 40.000 token to stored reward recipient
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: Some(vec![AssetToken {
+                        info: TokenInfo::Token {
+                            contract_addr: token_contract.to_string(),
+                        },
+                        amount: Uint128::from(40_000u128),
+                    }]),
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 claim remaining reward to USER_2
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: Some(USER_2.to_string()),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_3 received 40.000 token, USER_2 received 60.000 token, USER_1 received nothing
+            for (user, amount) in [(USER_1, 0u128), (USER_2, 60_000u128), (USER_3, 40_000u128)] {
+                let balance: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        token_contract.clone(),
+                        &cw20::Cw20QueryMsg::Balance {
+                            address: user.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(balance.balance, Uint128::from(amount));
+            }
+
+            // USER_1 clear reward recipient
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetRewardRecipient { recipient: None },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let reward_recipient: Option<Addr> = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::RewardRecipient {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(reward_recipient, None);
+        }
+
+        //         -------------- staker pending reward ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - at s20, USER_1 stake token_id 1 with lockup_term = 10s (30%) and token_id 2 with lockup_term = 30s (70%)
+        // - at s40:
+        // 	- token_id 1 is unlocked, pending_reward = 10(s) * 10.000 * 30 / 100 = 30.000
+        // 	- token_id 2 is in staking period without penalty, pending_reward = 20(s) * 10.000 * 70 / 100 = 140.000
+        // 	- total_pending_reward = 170.000
+        // - USER_1 claim 100.000 -> total_pending_reward = 70.000
+        #[test]
+        fn proper_operation_staker_pending_reward() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 and token_id 2 to USER_1
+            for token_id in ["1", "2"] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: USER_1.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            let token_info = TokenInfo::Token {
+                contract_addr: token_contract.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup_term = 10s and token_id 2 with lockup_term = 30s
+            for (token_id, lockup_term) in [("1", 10), ("2", 30)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(USER_1.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 20 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let staker_pending_reward: StakerPendingRewardResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::StakerPendingReward {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                staker_pending_reward,
+                StakerPendingRewardResponse {
+                    total_pending_reward: vec![AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(170_000u128),
+                    }],
+                    nfts: vec![
+                        NftPendingReward {
+                            key: NftKey {
+                                key: 1,
+                                lockup_term: 10,
+                            },
+                            token_id: "1".to_string(),
+                            collection_addr: Addr::unchecked(collection_contract.clone()),
+                            pending_reward: vec![AssetToken {
+                                info: token_info.clone(),
+                                amount: Uint128::from(30_000u128),
+                            }],
+                            unlocked: true,
+                        },
+                        NftPendingReward {
+                            key: NftKey {
+                                key: 1,
+                                lockup_term: 30,
+                            },
+                            token_id: "2".to_string(),
+                            collection_addr: Addr::unchecked(collection_contract.clone()),
+                            pending_reward: vec![AssetToken {
+                                info: token_info.clone(),
+                                amount: Uint128::from(140_000u128),
+                            }],
+                            unlocked: true,
+                        },
+                    ],
+                }
+            );
+
+            // USER_1 claim 100.000 token
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: Some(vec![AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(100_000u128),
+                    }]),
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // claimed reward is settled, remaining reward is still claimable
+            let staker_pending_reward: StakerPendingRewardResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::StakerPendingReward {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                staker_pending_reward.total_pending_reward,
+                vec![AssetToken {
+                    info: token_info,
+                    amount: Uint128::from(70_000u128),
+                }]
+            );
+            assert_eq!(
+                staker_pending_reward.nfts[1].pending_reward[0].amount,
+                Uint128::zero()
+            );
+        }
+
+        //         -------------- simulate stake ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - at s20, USER_2 stake token_id 2 with lockup_term = 30s (70%)
+        // - simulate staking 1 nft with lockup_term = 30s now
+        // 	- reward_per_nft = 30(s) * 10.000 * 70 / 100 / 2 = 105.000
+        // 	- annual_reward_per_nft = 105.000 * 31536000 / 30(s)
+        // - simulate staking 2 nfts with lockup_term = 30s at s100, token_id 2 is expired
+        // 	- reward_per_nft = 10(s) * 10.000 * 70 / 100 / 2 = 35.000
+        // - USER_1 stake token_id 1 with lockup_term = 30s at s20 -> pending_reward at s50 = 105.000
+        #[test]
+        fn proper_operation_simulate_stake() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1 and token_id 2 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            let token_info = TokenInfo::Token {
+                contract_addr: token_contract.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_2 stake token_id 2
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "2".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // simulate staking 1 nft now
+            let simulation: SimulateStakeResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::SimulateStake {
+                        collection_addr: collection_contract.clone(),
+                        token_id: None,
+                        lockup_term: 30,
+                        count: 1,
+                        at_time: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                simulation,
+                SimulateStakeResponse {
+                    lockup_term: 30,
+                    count: 1,
+                    start_time: current_block_time + 20,
+                    end_time: current_block_time + 50,
+                    reward_per_nft: vec![AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(105_000u128),
+                    }],
+                    total_reward: vec![AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(105_000u128),
+                    }],
+                    annual_reward_per_nft: vec![AssetToken {
+                        info: token_info.clone(),
+                        amount: Uint128::from(105_000u128 * 31_536_000 / 30),
+                    }],
+                }
+            );
+
+            // simulate staking 2 nfts at s100, lockup term is cut by end time of campaign
+            let simulation: SimulateStakeResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::SimulateStake {
+                        collection_addr: collection_contract.clone(),
+                        token_id: None,
+                        lockup_term: 30,
+                        count: 2,
+                        at_time: Some(current_block_time + 100),
+                    },
+                )
+                .unwrap();
+            assert_eq!(simulation.end_time, current_block_time + 110);
+            assert_eq!(
+                simulation.reward_per_nft[0].amount,
+                Uint128::from(35_000u128)
+            );
+            assert_eq!(simulation.total_reward[0].amount, Uint128::from(70_000u128));
+
+            // lockup term does not exist -> error
+            let response: Result<SimulateStakeResponse, _> = app.wrap().query_wasm_smart(
+                "contract3",
+                &CampaignQueryMsg::SimulateStake {
+                    collection_addr: collection_contract.clone(),
+                    token_id: None,
+                    lockup_term: 20,
+                    count: 1,
+                    at_time: None,
+                },
+            );
+            assert!(response.is_err());
+
+            // simulated time is in the past -> error
+            let response: Result<SimulateStakeResponse, _> = app.wrap().query_wasm_smart(
+                "contract3",
+                &CampaignQueryMsg::SimulateStake {
+                    collection_addr: collection_contract.clone(),
+                    token_id: None,
+                    lockup_term: 30,
+                    count: 1,
+                    at_time: Some(current_block_time + 15),
+                },
+            );
+            assert!(response.is_err());
+
+            // collection is not allowed in campaign -> error
+            let response: Result<SimulateStakeResponse, _> = app.wrap().query_wasm_smart(
+                "contract3",
+                &CampaignQueryMsg::SimulateStake {
+                    collection_addr: token_contract.clone(),
+                    token_id: None,
+                    lockup_term: 30,
+                    count: 1,
+                    at_time: None,
+                },
+            );
+            assert!(response.is_err());
+
+            // USER_1 stake token_id 1 as simulated
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 30 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 30 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(30),
+                height: app.block_info().height + 30,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // pending reward of token_id 1 is the same as simulated
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 2,
+                            lockup_term: 30,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.token_id, "1".to_string());
+            assert_eq!(nft_info.pending_reward, vec![Uint128::from(105_000u128)]);
+        }
+
+        //         -------------- reward index of lockup term ------------------
+        // - ADMIN create campaign with lockup term 10s (percent = 100) and 1.000.000 reward
+        // - USER_1 stake token_id 1, increase 3s, USER_1 stake token_id 2, USER_2 stake token_id 3
+        // - increase 20s -> all nfts are expired, nothing is updated in storage until next execute
+        // 	- token_id 1 pending_reward = 3(s) * 10.000 + 7(s) * 10.000 / 3 = 53.333
+        // 	- token_id 2, 3 pending_reward = 7(s) * 10.000 / 3 + 3(s) * 10.000 / 2 = 38.333
+        // - USER_1 claim reward -> expired nfts are removed from lockup term
+        // 	- total pending reward = 130.000 - 53.333 - 38.333 = 38.333, dust of expired nfts is released
+        // - ADMIN withdraw remaining reward after campaign ends, USER_2 can still claim 38.333
+        #[test]
+        fn proper_operation_reward_index() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1, 2 to USER_1 and token_id 3 to USER_2
+            for (token_id, owner) in [("1", USER_1), ("2", USER_1), ("3", USER_2)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 2,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            for (token_id, owner, increase) in
+                [("1", USER_1, 3), ("2", USER_1, 0), ("3", USER_2, 20)]
+            {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+
+                app.set_block(BlockInfo {
+                    time: app.block_info().time.plus_seconds(increase),
+                    height: app.block_info().height + increase,
+                    chain_id: app.block_info().chain_id,
+                });
+            }
+
+            // expired nfts are still in lockup term until next execute
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+            assert_eq!(term_reward_index.total_weight, 300);
+
+            let nfts: Vec<NftInfo> = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::Nfts {
+                        lockup_term: 10,
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                nfts.iter()
+                    .map(|nft_info| (nft_info.pending_reward[0], nft_info.is_end_reward))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (Uint128::new(53_333u128), true),
+                    (Uint128::new(38_333u128), true),
+                    (Uint128::new(38_333u128), true),
+                ]
+            );
+
+            // USER_1 claim reward
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_claimed, vec![Uint128::new(91_666u128)]);
+
+            // weight of expired nfts is removed from lockup term
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+            assert_eq!(term_reward_index.total_weight, 0);
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(38_333u128));
+
+            // increase 100 second to end campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(100),
+                height: app.block_info().height + 100,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_2 claim reward
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: USER_2.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(38_333u128));
+        }
+
+        //         -------------- fractional reward per second ------------------
+        // - ADMIN create campaign from s10 to s110 with lockup term 10s (percent = 100)
+        // - ADMIN add 999 reward token -> reward_per_second = 9.99 token
+        // - USER_1, USER_2, USER_3 stake token_id 1, 2, 3 -> all nfts are expired after 10s
+        // 	- token_id 1, 2, 3 pending_reward = 10(s) * 9.99 / 3 = 33.3 -> 33, dust = 0.3
+        // - increase 100s to end campaign, ADMIN withdraw remaining reward
+        // 	- withdraw reward = 999 - 3 * 33 = 900, rounding dust included
+        // - USER_1, USER_2, USER_3 claim 33 -> campaign balance = 0
+        #[test]
+        fn proper_operation_fractional_reward_per_second() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1, token_id 2 to USER_2 and token_id 3 to USER_3
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2), ("3", USER_3)] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 2,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add 999 reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(999u128),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // reward per second is not truncated to 9
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(999u128, 100u128)
+            );
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            for (token_id, owner) in [("1", USER_1), ("2", USER_2), ("3", USER_3)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 100 second to end campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(100),
+                height: app.block_info().height + 100,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let nfts: Vec<NftInfo> = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::Nfts {
+                        lockup_term: 10,
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                nfts.iter()
+                    .map(|nft_info| nft_info.pending_reward[0])
+                    .collect::<Vec<_>>(),
+                vec![Uint128::new(33u128); 3]
+            );
+
+            // ADMIN withdraw remaining reward
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::WithdrawReward {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // dust of expired nfts is not reserved
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+            assert_eq!(
+                term_reward_index.total_reward,
+                vec![Decimal256::from_ratio(999u128, 10u128)]
+            );
+            assert_eq!(
+                term_reward_index.total_dust,
+                vec![Decimal256::from_ratio(9u128, 10u128)]
+            );
+
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: ADMIN.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                balance.balance,
+                Uint128::from(MOCK_1000_TOKEN_AMOUNT - 999 + 900)
+            );
+
+            // every staker claim reward
+            for user in [USER_1, USER_2, USER_3] {
+                let response = app.execute_contract(
+                    Addr::unchecked(user.to_string()),
+                    Addr::unchecked("contract3"),
+                    &CampaignExecuteMsg::ClaimReward {
+                        amounts: None,
+                        recipient: None,
+                    },
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // no reward is left in campaign
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: "contract3".to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::zero());
+        }
+
+        //         -------------- paginated reset pool ------------------
+        // - ADMIN create campaign from s10 to s110 with lockup term 10s (percent = 100)
+        // - add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - USER_1 stake token_id 1, 2, 3, USER_2 stake token_id 4 -> all nfts are expired after 10s
+        // 	- token_id 1, 2, 3, 4 pending_reward = 10(s) * 10.000 / 4 = 25.000
+        // - ADMIN reset pool with limit 3 -> token_id 1, 2, 3 are settled, reset is in progress
+        // - USER_2 claim 25.000 while reset is in progress
+        // - ADMIN reset pool with limit 3 -> reset is completed
+        #[test]
+        fn proper_operation_paginated_reset_pool() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1, 2, 3 to USER_1 and token_id 4 to USER_2
+            let nfts = [("1", USER_1), ("2", USER_1), ("3", USER_1), ("4", USER_2)];
+            for (token_id, owner) in nfts {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: owner.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 3,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            for (token_id, owner) in nfts {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(owner.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 20 second, all nfts are expired
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // no reset is in progress, 4 expired nfts would be settled by reset
+            let progress: ResetPoolProgressResponse = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::ResetPoolProgress {})
+                .unwrap();
+            assert_eq!(
+                progress,
+                ResetPoolProgressResponse {
+                    reset_time: None,
+                    terms: vec![TermResetProgress {
+                        term_value: 10,
+                        settled: 0,
+                        remaining: 4,
+                    }],
+                }
+            );
+
+            // only admin can reset pool
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ResetPool { limit: Some(3) },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ResetPool { limit: Some(3) },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let progress: ResetPoolProgressResponse = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::ResetPoolProgress {})
+                .unwrap();
+            assert_eq!(
+                progress,
+                ResetPoolProgressResponse {
+                    reset_time: Some(app.block_info().time.seconds()),
+                    terms: vec![TermResetProgress {
+                        term_value: 10,
+                        settled: 3,
+                        remaining: 1,
+                    }],
+                }
+            );
+
+            // settled nfts earn reward until their end time
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(25_000u128)]);
+            assert!(nft_info.is_end_reward);
+
+            // USER_2 claim reward while reset is in progress
+            let response = app.execute_contract(
+                Addr::unchecked(USER_2.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: USER_2.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(25_000u128));
+
+            // reset is completed
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ResetPool { limit: Some(3) },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let progress: ResetPoolProgressResponse = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::ResetPoolProgress {})
+                .unwrap();
+            assert_eq!(progress.reset_time, None);
+            assert_eq!(progress.terms[0].remaining, 0);
+
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+            assert_eq!(term_reward_index.total_weight, 0);
+
+            // USER_1 claim reward of 3 nfts
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let balance: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    token_contract.clone(),
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: USER_1.to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance.balance, Uint128::new(75_000u128));
+        }
+
+        //         -------------- migrate legacy factory ------------------
+        // - factory stored campaigns with single reward token and collection, without code id
+        // - migrate factory with legacy code id 7 -> campaigns are rewritten in current shape
+        // - ADMIN migrate all campaigns to code id 8 -> code id of campaigns is updated
+        // - migrate factory again -> nothing is rewritten
+        #[test]
+        fn proper_operation_migrate_legacy_factory() {
+            #[cw_serde]
+            struct LegacyFactoryCampaign {
+                owner: Addr,
+                campaign_addr: Addr,
+                reward_token: TokenInfo,
+                allowed_collection: Addr,
+            }
+            const LEGACY_CAMPAIGNS: Map<u64, LegacyFactoryCampaign> = Map::new("campaigns");
+
+            let mut deps = mock_dependencies();
+
+            // instantiate factory, then write campaigns as legacy factory stored them
+            let response = factory_instantiate(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &[]),
+                crate::msg::InstantiateMsg {
+                    owner: ADMIN.to_string(),
+                    campaign_code_id: 2,
+                    allow_create_for_all: false,
+                },
+            );
+            assert!(response.is_ok());
+
+            for campaign_id in 1..=2u64 {
+                LEGACY_CAMPAIGNS
+                    .save(
+                        deps.as_mut().storage,
+                        campaign_id,
+                        &LegacyFactoryCampaign {
+                            owner: Addr::unchecked(ADMIN),
+                            campaign_addr: Addr::unchecked(format!("campaign{}", campaign_id)),
+                            reward_token: TokenInfo::NativeToken {
+                                denom: NATIVE_DENOM.to_string(),
+                            },
+                            allowed_collection: Addr::unchecked("collection"),
+                        },
+                    )
+                    .unwrap();
+            }
+            NUMBER_OF_CAMPAIGNS.save(deps.as_mut().storage, &2).unwrap();
+
+            // legacy campaigns can not be loaded before migration
+            let response = factory_execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &[]),
+                crate::msg::ExecuteMsg::MigrateCampaigns {
+                    new_code_id: 8,
+                    campaigns: CampaignSelection::Range {
+                        start_after: None,
+                        limit: None,
+                    },
+                    msg: to_binary(&CampaignMigrateMsg {}).unwrap(),
+                },
+            );
+            assert!(response.is_err());
+
+            let response = factory_migrate(
+                deps.as_mut(),
+                mock_env(),
+                crate::msg::MigrateMsg {
+                    legacy_code_id: Some(7),
+                },
+            );
+            assert!(response.is_ok());
+
+            let campaigns: Vec<FactoryCampaign> = from_binary(
+                &factory_query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Campaigns {
+                        start_after: None,
+                        limit: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                campaigns[0],
+                FactoryCampaign {
+                    owner: Addr::unchecked(ADMIN),
+                    campaign_addr: Addr::unchecked("campaign1"),
+                    reward_tokens: vec![TokenInfo::NativeToken {
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: "collection".to_string(),
+                        weight: 1,
+                    }],
+                    code_id: 7,
+                }
+            );
+            assert_eq!(campaigns.len(), 2);
+
+            // migrate all legacy campaigns to new code id
+            let response = factory_execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &[]),
+                crate::msg::ExecuteMsg::MigrateCampaigns {
+                    new_code_id: 8,
+                    campaigns: CampaignSelection::Range {
+                        start_after: None,
+                        limit: None,
+                    },
+                    msg: to_binary(&CampaignMigrateMsg {}).unwrap(),
+                },
+            )
+            .unwrap();
+            assert_eq!(response.messages.len(), 2);
+
+            let campaign: FactoryCampaign = from_binary(
+                &factory_query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::Campaign { campaign_id: 2 },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(campaign.code_id, 8);
+
+            // migrated campaigns are not rewritten again
+            let response = factory_migrate(
+                deps.as_mut(),
+                mock_env(),
+                crate::msg::MigrateMsg {
+                    legacy_code_id: None,
+                },
+            )
+            .unwrap();
+            assert!(response
+                .attributes
+                .contains(&Attribute::new("migrated_campaigns", "0")));
+        }
+
+        //         -------------- unstake with no limit per staker ------------------
+        // - campaign with limit_per_staker = 0 (no limit)
+        // - USER_1 stake token_id 1, 2, 3 with lockup_term = 10s
+        // - increase 12s
+        // - USER_1 un stake token_id 1, 2 -> ok
+        // - USER_1 un stake all -> token_id 3 is returned
+        #[test]
+        fn proper_operation_unstake_nfts_with_no_limit_per_staker() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1, 2, 3 to USER_1
+            for token_id in ["1", "2", "3"] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: USER_1.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 0,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // stake token_id 1, 2, 3 with lockup term 10s
+            for token_id in ["1", "2", "3"] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(USER_1.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 12 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(12),
+                height: app.block_info().height + 12,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // un stake token_id 1, 2 is not limited
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNfts {
+                    keys: vec![
+                        NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                        NftKey {
+                            key: 2,
+                            lockup_term: 10,
+                        },
+                    ],
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeAll {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // all nfts are returned to USER_1
+            for token_id in ["1", "2", "3"] {
+                let owner: OwnerOfResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        collection_contract.clone(),
+                        &Cw721QueryMsg::OwnerOf {
+                            token_id: token_id.to_string(),
+                            include_expired: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(owner.owner, USER_1.to_string());
+            }
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert!(staker_info.keys.is_empty());
+        }
+
+        //         -------------- claim reward before early unstake ------------------
+        // - ADMIN create campaign with lockup term 10s, 100%, penalty 50%
+        // - USER_1 stake token_id 1
+        // - increase 4s, token_id 1 is locked -> total_pending_reward = 0
+        // - USER_1 claim reward -> reward of token_id 1 is kept in nft, nothing is claimed
+        // - USER_1 un stake token_id 1
+        // 	- pending_reward = 4(s) * 10.000 = 40.000, penalty = 20.000
+        // 	- USER_1 reward_debt = 20.000
+        #[test]
+        fn proper_operation_claim_reward_before_early_unstake() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: Some(EarlyUnstakePenalty {
+                            percent: Uint128::new(50u128),
+                            decay: false,
+                            recipient: None,
+                        }),
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 10s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 4 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(4),
+                height: app.block_info().height + 4,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // reward of locked token_id 1 is not claimable
+            let staker_pending_reward: StakerPendingRewardResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::StakerPendingReward {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                staker_pending_reward.total_pending_reward[0].amount,
+                Uint128::zero()
+            );
+            assert!(!staker_pending_reward.nfts[0].unlocked);
+            assert_eq!(
+                staker_pending_reward.nfts[0].pending_reward[0].amount,
+                Uint128::new(40_000u128)
+            );
+
+            // USER_1 claim reward while token_id 1 is in staking period
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // reward of token_id 1 is not moved to reward_debt
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::zero()]);
+            assert_eq!(staker_info.reward_claimed, vec![Uint128::zero()]);
+
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(40_000u128)]);
+
+            // USER_1 un stake token_id 1 before end of lockup term
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key: 1,
+                        lockup_term: 10,
+                    },
+                    token_id: "1".to_string(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // penalty is taken from all reward earned since stake
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(20_000u128)]);
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_penalty,
+                Uint128::new(20_000u128)
+            );
+        }
+
+        //         -------------- move start time earlier ------------------
+        // - ADMIN create campaign from s60 to s110, add 1000.000 reward token
+        // - before campaign starts, ADMIN update start_time = s10
+        // 	- reward_per_second = 1000.000 / 100(s) = 10.000 token
+        // 	- reward index of lockup term starts from s10
+        // - increase 20s, USER_1 stake token_id 1 with lockup_term = 10s (100%)
+        // - increase 4s -> token_id 1 pending_reward = 4(s) * 10.000 = 40.000
+        #[test]
+        fn proper_operation_update_campaign_start_time_earlier() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 60,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN move start time earlier
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UpdateCampaign {
+                    update_campaign: UpdateCampaignInfo {
+                        campaign_name: None,
+                        campaign_image: None,
+                        campaign_description: None,
+                        limit_per_staker: None,
+                        lockup_term: None,
+                        start_time: Some(current_block_time + 10),
+                    },
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].reward_per_second,
+                Decimal256::from_ratio(10_000u128, 1u128)
+            );
+
+            // reward index of lockup term starts from new start time
+            let term_reward_index: TermRewardIndex = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap();
+            assert_eq!(term_reward_index.last_update, current_block_time + 10);
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 10s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 4 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(4),
+                height: app.block_info().height + 4,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(40_000u128)]);
+        }
+
+        //         -------------- migrate campaign version checks ------------------
+        // - campaign of newer version -> error, downgrade is not allowed
+        // - other contract -> error, only campaign contract can be migrated
+        // - campaign of older version -> state is migrated to latest state version
+        #[test]
+        fn proper_operation_migrate_campaign_version_checks() {
+            let mut deps = mock_dependencies();
+
+            // downgrade is not allowed
+            cw2::set_contract_version(deps.as_mut().storage, "crates.io:campaign", "99.0.0")
+                .unwrap();
+            let response = campaign_migrate(deps.as_mut(), mock_env(), CampaignMigrateMsg {});
+            assert!(matches!(
+                response,
+                Err(ContractError::CannotMigrateVersion { .. })
+            ));
+
+            // only campaign contract can be migrated
+            cw2::set_contract_version(deps.as_mut().storage, "crates.io:other", "0.1.0").unwrap();
+            let response = campaign_migrate(deps.as_mut(), mock_env(), CampaignMigrateMsg {});
+            assert!(matches!(response, Err(ContractError::CannotMigrate { .. })));
+
+            // campaign of older version runs all data migrations
+            let mut deps = mock_dependencies();
+            cw2::set_contract_version(deps.as_mut().storage, "crates.io:campaign", "0.1.0")
+                .unwrap();
+            save_v1_campaign(deps.as_mut().storage);
+
+            let response =
+                campaign_migrate(deps.as_mut(), mock_env(), CampaignMigrateMsg {}).unwrap();
+            assert!(response.attributes.contains(&Attribute::new(
+                "state_version",
+                LATEST_STATE_VERSION.to_string()
+            )));
+            assert_eq!(
+                STATE_VERSION.load(deps.as_ref().storage).unwrap(),
+                LATEST_STATE_VERSION
+            );
+            assert_eq!(
+                cw2::get_contract_version(deps.as_ref().storage)
+                    .unwrap()
+                    .version,
+                "1.0.0"
+            );
+        }
+
+        //         -------------- migrate campaign state ------------------
+        // - save v1 state: term 10s has token_id 1, 2 staked in one message but counted once,
+        //   term 30s has token_id 3
+        // - rebuild_staking_by_term -> term 10s has 2 nfts staking
+        // - migrate_to_reward_tokens -> single reward token and rewards are moved to lists
+        // - migrate_to_allowed_collections -> single collection has weight 1
+        // - scale_weights -> weights are in hundredths
+        #[test]
+        fn proper_operation_migrate_campaign_state() {
+            let mut deps = mock_dependencies();
+            save_v1_campaign(deps.as_mut().storage);
+
+            // total staking and expiration times are rebuilt from nfts
+            migrations::rebuild_staking_by_term(deps.as_mut().storage).unwrap();
+
+            let term_reward_rates = v4::TERM_REWARD_RATES
+                .load(deps.as_ref().storage, 10)
+                .unwrap();
+            assert_eq!(term_reward_rates.last().unwrap().rate, 2);
+            assert_eq!(
+                v4::TOTAL_STAKING_BY_TERM
+                    .load(deps.as_ref().storage, 10)
+                    .unwrap(),
+                2
+            );
+            assert_eq!(
+                v1::TERM_EXPIRATION_TIMES
+                    .load(deps.as_ref().storage, 10)
+                    .unwrap(),
+                vec![1030, 1030]
+            );
+            assert_eq!(
+                v4::TOTAL_STAKING_BY_TERM
+                    .load(deps.as_ref().storage, 30)
+                    .unwrap(),
+                1
+            );
+            assert_eq!(
+                v1::TERM_EXPIRATION_TIMES
+                    .load(deps.as_ref().storage, 30)
+                    .unwrap(),
+                vec![1050]
+            );
+
+            // single reward token is moved to reward_tokens
+            migrations::migrate_to_reward_tokens(deps.as_mut().storage).unwrap();
+
+            let campaign_info = v2::CAMPAIGN_INFO.load(deps.as_ref().storage).unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens,
+                vec![RewardToken {
+                    info: TokenInfo::Token {
+                        contract_addr: "token".to_string(),
+                    },
+                    amount: Uint128::new(1_000_000u128),
+                    reward_per_second: Decimal256::from_ratio(10_000u128, 1u128),
+                    total_reward: Uint128::new(1_000_000u128),
+                    total_reward_claimed: Uint128::new(7_000u128),
+                    total_penalty: Uint128::zero(),
+                }]
+            );
+            let nft_info = v2::NFTS.load(deps.as_ref().storage, (1, 10)).unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(3_000u128)]);
+            let staker_info = STAKERS_INFO
+                .load(deps.as_ref().storage, Addr::unchecked(USER_1))
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(5_000u128)]);
+            assert_eq!(staker_info.reward_claimed, vec![Uint128::new(7_000u128)]);
+            assert_eq!(staker_info.keys.len(), 3);
+            assert_eq!(
+                v4::PREVIOUS_TOTAL_REWARD
+                    .load(deps.as_ref().storage)
+                    .unwrap(),
+                vec![Uint128::new(100u128)]
+            );
+
+            // single collection is moved to allowed_collections with weight 1
+            migrations::migrate_to_allowed_collections(deps.as_mut().storage).unwrap();
+
+            let campaign_info = CAMPAIGN_INFO.load(deps.as_ref().storage).unwrap();
+            assert_eq!(
+                campaign_info.allowed_collections,
+                vec![AllowedCollection {
+                    collection_addr: "collection".to_string(),
+                    weight: 1,
+                }]
+            );
+            assert!(campaign_info.trait_multipliers.is_empty());
+            assert!(!campaign_info.cancelled);
+            let nft_info = NFTS.load(deps.as_ref().storage, (1, 10)).unwrap();
+            assert_eq!(nft_info.collection_addr, Addr::unchecked("collection"));
+            assert_eq!(nft_info.weight, 1);
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(3_000u128)]);
+            let expiration_times = v4::TERM_EXPIRATION_TIMES
+                .load(deps.as_ref().storage, 10)
+                .unwrap();
+            assert_eq!(
+                expiration_times,
+                vec![
+                    v4::ExpirationTime {
+                        end_time: 1030,
+                        weight: 1,
+                    },
+                    v4::ExpirationTime {
+                        end_time: 1030,
+                        weight: 1,
+                    },
+                ]
+            );
+
+            // weights are scaled to hundredths
+            migrations::scale_weights(deps.as_mut().storage).unwrap();
+
+            for key in [(1, 10), (2, 10), (1, 30)] {
+                let nft_info = NFTS.load(deps.as_ref().storage, key).unwrap();
+                assert_eq!(nft_info.weight, WEIGHT_PRECISION);
+            }
+            let term_reward_rates = v4::TERM_REWARD_RATES
+                .load(deps.as_ref().storage, 10)
+                .unwrap();
+            assert_eq!(term_reward_rates.last().unwrap().rate, 2 * WEIGHT_PRECISION);
+            assert_eq!(
+                v4::TOTAL_STAKING_BY_TERM
+                    .load(deps.as_ref().storage, 10)
+                    .unwrap(),
+                2 * WEIGHT_PRECISION
+            );
+            let expiration_times = v4::TERM_EXPIRATION_TIMES
+                .load(deps.as_ref().storage, 30)
+                .unwrap();
+            assert_eq!(
+                expiration_times,
+                vec![v4::ExpirationTime {
+                    end_time: 1050,
+                    weight: WEIGHT_PRECISION,
+                }]
+            );
+        }
+
+        // campaign of state version 0, from s1000 to s1100 with reward_per_second = 10.000:
+        // - USER_1 stake token_id 1, 2 with lockup_term = 10s in one message at s1020
+        //   -> reward rates of term 10s count only one nft
+        // - USER_1 stake token_id 3 with lockup_term = 30s at s1020
+        fn save_v1_campaign(storage: &mut dyn cosmwasm_std::Storage) {
+            let lockup_term = vec![
+                LockupTerm {
+                    value: 10,
+                    percent: Uint128::new(30u128),
+                    early_unstake_penalty: None,
+                },
+                LockupTerm {
+                    value: 30,
+                    percent: Uint128::new(70u128),
+                    early_unstake_penalty: None,
+                },
+            ];
+
+            v1::CAMPAIGN_INFO
+                .save(
+                    storage,
+                    &v1::CampaignInfo {
+                        owner: Addr::unchecked(ADMIN),
+                        campaign_name: "campaign name".to_string(),
+                        campaign_image: "campaign name".to_string(),
+                        campaign_description: "campaign name".to_string(),
+                        total_reward_claimed: Uint128::new(7_000u128),
+                        total_reward: Uint128::new(1_000_000u128),
+                        limit_per_staker: 4,
+                        reward_token: AssetToken {
+                            info: TokenInfo::Token {
+                                contract_addr: "token".to_string(),
+                            },
+                            amount: Uint128::new(1_000_000u128),
+                        },
+                        allowed_collection: Addr::unchecked("collection"),
+                        lockup_term: lockup_term.clone(),
+                        reward_per_second: Uint128::new(10_000u128),
+                        start_time: 1000,
+                        end_time: 1100,
+                    },
+                )
+                .unwrap();
+
+            let mut keys = vec![];
+            for (key, token_id, term) in [
+                (1, "1", &lockup_term[0]),
+                (2, "2", &lockup_term[0]),
+                (1, "3", &lockup_term[1]),
+            ] {
+                v1::NFTS
+                    .save(
+                        storage,
+                        (key, term.value),
+                        &v1::NftInfo {
+                            key,
+                            token_id: token_id.to_string(),
+                            owner: Addr::unchecked(USER_1),
+                            pending_reward: Uint128::new(3_000u128),
+                            lockup_term: term.clone(),
+                            is_end_reward: false,
+                            start_time: 1020,
+                            time_calc: 1020,
+                            end_time: 1020 + term.value,
+                        },
+                    )
+                    .unwrap();
+                NUMBER_OF_NFTS.save(storage, term.value, &key).unwrap();
+                keys.push(NftKey {
+                    key,
+                    lockup_term: term.value,
+                });
+            }
+
+            v1::STAKERS_INFO
+                .save(
+                    storage,
+                    Addr::unchecked(USER_1),
+                    &v1::StakerRewardAssetInfo {
+                        keys,
+                        reward_debt: Uint128::new(5_000u128),
+                        reward_claimed: Uint128::new(7_000u128),
+                    },
+                )
+                .unwrap();
+            v1::PREVIOUS_TOTAL_REWARD
+                .save(storage, &Uint128::new(100u128))
+                .unwrap();
+
+            for term in lockup_term.iter() {
+                v4::TERM_REWARD_RATES
+                    .save(
+                        storage,
+                        term.value,
+                        &vec![v4::RewardRate {
+                            timestamp: 1020,
+                            rate: 1,
+                        }],
+                    )
+                    .unwrap();
+            }
+        }
+
+        //         -------------- migrate reward rates to reward index ------------------
+        // - save state version 4 of campaign from s1000 to s1100, reward_per_second = 10.000:
+        // 	- token_id 1 staked at s1020, token_id 2 staked at s1021 with lockup_term = 10s (30%)
+        // 	- token_id 3 staked at s1020 with lockup_term = 30s (70%)
+        // 	- reward rates of term 10s were last updated at s1021, token_id 1 pending_reward = 3.000
+        // - migrate_to_reward_index, query at s1027 -> same as reward rates:
+        // 	- token_id 1 pending_reward = 3.000 + 6(s) * 10.000 * 30 / 100 / 2 = 12.000
+        // 	- token_id 2 pending_reward = 6(s) * 10.000 * 30 / 100 / 2 = 9.000
+        // 	- token_id 3 pending_reward = 7(s) * 10.000 * 70 / 100 = 49.000
+        // 	- total_pending_reward = 12.000 + 9.000 + 49.000 = 70.000
+        #[test]
+        fn proper_operation_migrate_to_reward_index() {
+            let mut deps = mock_dependencies();
+
+            let lockup_term = vec![
+                LockupTerm {
+                    value: 10,
+                    percent: Uint128::new(30u128),
+                    early_unstake_penalty: None,
+                },
+                LockupTerm {
+                    value: 30,
+                    percent: Uint128::new(70u128),
+                    early_unstake_penalty: None,
+                },
+            ];
+
+            CAMPAIGN_INFO
+                .save(
+                    deps.as_mut().storage,
+                    &CampaignInfo {
+                        owner: Addr::unchecked(ADMIN),
+                        campaign_name: "campaign name".to_string(),
+                        campaign_image: "campaign name".to_string(),
+                        campaign_description: "campaign name".to_string(),
+                        limit_per_staker: 4,
+                        reward_tokens: vec![RewardToken {
+                            info: TokenInfo::Token {
+                                contract_addr: "token".to_string(),
+                            },
+                            amount: Uint128::new(1_000_000u128),
+                            reward_per_second: Decimal256::from_ratio(10_000u128, 1u128),
+                            total_reward: Uint128::new(1_000_000u128),
+                            total_reward_claimed: Uint128::zero(),
+                            total_penalty: Uint128::zero(),
+                        }],
+                        allowed_collections: vec![AllowedCollection {
+                            collection_addr: "collection".to_string(),
+                            weight: 1,
+                        }],
+                        lockup_term: lockup_term.clone(),
+                        start_time: 1000,
+                        end_time: 1100,
+                        trait_multipliers: vec![],
+                        cancelled: false,
+                    },
+                )
+                .unwrap();
+
+            let mut keys = vec![];
+            for (key, token_id, term, start_time, time_calc, pending_reward) in [
+                (1, "1", &lockup_term[0], 1020, 1021, 3_000u128),
+                (2, "2", &lockup_term[0], 1021, 1021, 0u128),
+                (1, "3", &lockup_term[1], 1020, 1020, 0u128),
+            ] {
+                NFTS.save(
+                    deps.as_mut().storage,
+                    (key, term.value),
+                    &NftInfo {
+                        key,
+                        token_id: token_id.to_string(),
+                        collection_addr: Addr::unchecked("collection"),
+                        weight: WEIGHT_PRECISION,
+                        owner: Addr::unchecked(USER_1),
+                        pending_reward: vec![Uint128::new(pending_reward)],
+                        lockup_term: term.clone(),
+                        is_end_reward: false,
+                        start_time,
+                        time_calc,
+                        end_time: start_time + term.value,
+                    },
+                )
+                .unwrap();
+                NUMBER_OF_NFTS
+                    .save(deps.as_mut().storage, term.value, &key)
+                    .unwrap();
+                keys.push(NftKey {
+                    key,
+                    lockup_term: term.value,
+                });
+            }
+
+            STAKERS_INFO
+                .save(
+                    deps.as_mut().storage,
+                    Addr::unchecked(USER_1),
+                    &StakerRewardAssetInfo {
+                        keys,
+                        reward_debt: vec![Uint128::zero()],
+                        reward_claimed: vec![Uint128::zero()],
+                    },
+                )
+                .unwrap();
+
+            // reward rates of term 10s changed when token_id 2 was staked
+            v4::TERM_REWARD_RATES
+                .save(
+                    deps.as_mut().storage,
+                    10,
+                    &vec![
+                        v4::RewardRate {
+                            timestamp: 1020,
+                            rate: WEIGHT_PRECISION,
+                        },
+                        v4::RewardRate {
+                            timestamp: 1021,
+                            rate: 2 * WEIGHT_PRECISION,
+                        },
+                    ],
+                )
+                .unwrap();
+            v4::TERM_REWARD_RATES
+                .save(
+                    deps.as_mut().storage,
+                    30,
+                    &vec![v4::RewardRate {
+                        timestamp: 1020,
+                        rate: WEIGHT_PRECISION,
+                    }],
+                )
+                .unwrap();
+            v4::TOTAL_STAKING_BY_TERM
+                .save(deps.as_mut().storage, 10, &(2 * WEIGHT_PRECISION))
+                .unwrap();
+            v4::TOTAL_STAKING_BY_TERM
+                .save(deps.as_mut().storage, 30, &WEIGHT_PRECISION)
+                .unwrap();
+            v4::PREVIOUS_TOTAL_REWARD
+                .save(deps.as_mut().storage, &vec![Uint128::zero()])
+                .unwrap();
+
+            migrations::migrate_to_reward_index(deps.as_mut().storage).unwrap();
+
+            // legacy reward state is removed
+            assert!(v4::TERM_REWARD_RATES
+                .may_load(deps.as_ref().storage, 10)
+                .unwrap()
+                .is_none());
+            assert!(v4::PREVIOUS_TOTAL_REWARD
+                .may_load(deps.as_ref().storage)
+                .unwrap()
+                .is_none());
+
+            let term_reward_index: TermRewardIndex = from_binary(
+                &campaign_query(
+                    deps.as_ref(),
+                    mock_env(),
+                    CampaignQueryMsg::TermRewardIndex { term_value: 10 },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(term_reward_index.total_weight, 2 * WEIGHT_PRECISION);
+            assert_eq!(term_reward_index.last_update, 1021);
+
+            let mut env = mock_env();
+            env.block.time = Timestamp::from_seconds(1027);
+
+            for (key, lockup_term, pending_reward) in
+                [(1, 10, 12_000u128), (2, 10, 9_000u128), (1, 30, 49_000u128)]
+            {
+                let nft_info: NftInfo = from_binary(
+                    &campaign_query(
+                        deps.as_ref(),
+                        env.clone(),
+                        CampaignQueryMsg::NftInfo {
+                            nft_key: NftKey { key, lockup_term },
+                        },
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+                assert_eq!(nft_info.pending_reward, vec![Uint128::new(pending_reward)]);
+            }
+
+            let total_pending_reward: Vec<AssetToken> = from_binary(
+                &campaign_query(
+                    deps.as_ref(),
+                    env.clone(),
+                    CampaignQueryMsg::TotalPendingReward {},
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(70_000u128));
+
+            let staker_pending_reward: StakerPendingRewardResponse = from_binary(
+                &campaign_query(
+                    deps.as_ref(),
+                    env,
+                    CampaignQueryMsg::StakerPendingReward {
+                        owner: Addr::unchecked(USER_1),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                staker_pending_reward.total_pending_reward[0].amount,
+                Uint128::new(70_000u128)
+            );
+        }
+
+        //         -------------- stake with overflowing end time ------------------
+        // - ADMIN create campaign with lockup term u64::MAX
+        // - USER_1 stake token_id 1 -> error, end time of nft overflows
+        #[test]
+        fn wrong_operation_stake_end_time_overflow() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // mint token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: u64::MAX,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // Mint 1000 tokens to ADMIN and add them as reward token
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft {
+                    lockup_term: u64::MAX,
+                })
+                .unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_err());
+        }
+
+        //         -------------- first reward deposit after campaign starts ------------------
+        // - ADMIN create campaign from s10 to s110 with cw20 and native reward tokens
+        // - ADMIN add 1000.000 cw20 token -> reward_per_second = 10.000 token
+        // - increase 20s, USER_1 stake token_id 1 with lockup_term = 10s (100%)
+        // - increase 5s, ADMIN add 425.000 ueaura at s25
+        // 	- ueaura is distributed over remaining 85s -> reward_per_second = 5.000 ueaura
+        // - increase 5s -> token_id 1 pending_reward:
+        // 	- 10(s) * 10.000 = 100.000 token
+        // 	- 5(s) * 5.000 = 25.000 ueaura
+        #[test]
+        fn proper_operation_first_deposit_after_start() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // cw20 and native token info
+            let token_info = TokenInfo::Token {
+                contract_addr: token_contract.to_string(),
+            };
+            let native_token_info = TokenInfo::NativeToken {
+                denom: NATIVE_DENOM.to_string(),
+            };
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![token_info.clone(), native_token_info.clone()],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![LockupTerm {
+                        value: 10,
+                        percent: Uint128::new(100u128),
+                        early_unstake_penalty: None,
+                    }],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // ADMIN add cw20 reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 10s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 5 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(5),
+                height: app.block_info().height + 5,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // ADMIN add native reward token for the first time
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::AddRewardToken {
+                    reward_token: AssetToken {
+                        info: native_token_info.clone(),
+                        amount: Uint128::from(425_000u128),
+                    },
+                },
+                &coins(425_000u128, NATIVE_DENOM),
+            );
+            assert!(response.is_ok());
+
+            // native reward is distributed over remaining duration
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[1].reward_per_second,
+                Decimal256::from_ratio(5_000u128, 1u128)
+            );
+
+            // increase 5 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(5),
+                height: app.block_info().height + 5,
+                chain_id: app.block_info().chain_id,
+            });
+
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 10,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                nft_info.pending_reward,
+                vec![Uint128::new(100_000u128), Uint128::new(25_000u128)]
+            );
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(
+                total_pending_reward,
+                vec![
+                    AssetToken {
+                        info: token_info,
+                        amount: Uint128::from(100_000u128),
+                    },
+                    AssetToken {
+                        info: native_token_info,
+                        amount: Uint128::from(25_000u128),
+                    },
+                ]
+            );
+        }
+
+        //         -------------- emergency withdraw of unlocked nft ------------------
+        // - ADMIN create campaign from s10 to s110, add 1000.000 reward token -> reward_per_second = 10.000 token
+        // - at s20, USER_1 stake token_id 1 with lockup_term = 10s (30%), token_id 2 with lockup_term = 30s (70%)
+        // - at s32, ADMIN enable emergency mode, USER_1 withdraw all nfts
+        // 	- token_id 1 is unlocked, pending_reward = 10(s) * 10.000 * 30 / 100 = 30.000 is kept
+        // 	- token_id 2 is locked, pending_reward = 12(s) * 10.000 * 70 / 100 = 84.000 is forfeited
+        #[test]
+        fn proper_operation_emergency_withdraw_unlocked_nft() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 and token_id 2 to USER_1
+            for token_id in ["1", "2"] {
+                let mint_nft_msg = Cw721MintMsg {
+                    token_id: token_id.to_string(),
+                    owner: USER_1.to_string(),
+                    token_uri: None,
+                    extension: None,
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(ADMIN.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: None,
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: None,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 10s, token_id 2 with lockup term 30s
+            for (token_id, lockup_term) in [("1", 10), ("2", 30)] {
+                let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                    contract: "contract3".to_string(),
+                    token_id: token_id.to_string(),
+                    msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term }).unwrap(),
+                };
+
+                let response = app.execute_contract(
+                    Addr::unchecked(USER_1.to_string()),
+                    Addr::unchecked(collection_contract.clone()),
+                    &send_nft_msg,
+                    &[],
+                );
+                assert!(response.is_ok());
+            }
+
+            // increase 12 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(12),
+                height: app.block_info().height + 12,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // ADMIN enable emergency mode
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::SetEmergency { emergency: true },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // USER_1 withdraw all nfts
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::EmergencyWithdraw {},
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // all nfts are returned to USER_1
+            for token_id in ["1", "2"] {
+                let owner: OwnerOfResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        collection_contract.clone(),
+                        &Cw721QueryMsg::OwnerOf {
+                            token_id: token_id.to_string(),
+                            include_expired: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(owner.owner, USER_1.to_string());
+            }
+
+            // reward of unlocked token_id 1 is kept, reward of locked token_id 2 is forfeited
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert!(staker_info.keys.is_empty());
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(30_000u128)]);
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_penalty,
+                Uint128::new(84_000u128)
+            );
+
+            let total_pending_reward: Vec<AssetToken> = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::TotalPendingReward {})
+                .unwrap();
+            assert_eq!(total_pending_reward[0].amount, Uint128::new(30_000u128));
+        }
+
+        //         -------------- change lockup term with penalty ------------------
+        // - ADMIN create campaign with lockup terms 10s (30%) and 30s (70%), both with penalty 50%
+        // - USER_1 stake token_id 1 with lockup_term = 10s
+        // - increase 4s
+        // - USER_1 change lockup term of token_id 1 to 10s -> error, lockup term is not longer
+        // - USER_1 change lockup term of token_id 1 to 30s
+        // 	- token_id 1 pending_reward = 4(s) * 10.000 * 30 / 100 = 12.000 is kept in nft
+        // - USER_1 claim reward -> nothing is claimed, token_id 1 is in staking period
+        // - increase 6s, USER_1 un stake token_id 1
+        // 	- pending_reward = 12.000 + 6(s) * 10.000 * 70 / 100 = 54.000, penalty = 27.000
+        // 	- USER_1 reward_debt = 27.000
+        #[test]
+        fn proper_operation_change_lockup_term_with_penalty() {
+            // get integration test app and contracts
+            let (mut app, contracts) = instantiate_contracts();
+
+            // get factory contract
+            let factory_contract = &contracts[0].contract_addr;
+            // get lp token contract
+            let token_contract = &contracts[1].contract_addr;
+            // get collection contract
+            let collection_contract = &contracts[2].contract_addr;
+
+            // Mint 1000 tokens to ADMIN
+            let mint_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Mint {
+                recipient: ADMIN.to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &mint_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // mint token_id 1 to USER_1
+            let mint_nft_msg = Cw721MintMsg {
+                token_id: "1".to_string(),
+                owner: USER_1.to_string(),
+                token_uri: None,
+                extension: None,
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &Cw721ExecuteMsg::Mint(mint_nft_msg),
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // get current block time
+            let current_block_time = app.block_info().time.seconds();
+
+            let penalty = Some(EarlyUnstakePenalty {
+                percent: Uint128::new(50u128),
+                decay: false,
+                recipient: None,
+            });
+
+            // create campaign contract by factory contract
+            let create_campaign_msg = crate::msg::ExecuteMsg::CreateCampaign {
+                create_campaign: CreateCampaign {
+                    owner: ADMIN.to_string(),
+                    campaign_name: "campaign name".to_string(),
+                    campaign_image: "campaign name".to_string(),
+                    campaign_description: "campaign name".to_string(),
+                    start_time: current_block_time + 10,
+                    end_time: current_block_time + 110,
+                    limit_per_staker: 4,
+                    reward_tokens: vec![TokenInfo::Token {
+                        contract_addr: token_contract.to_string(),
+                    }],
+                    allowed_collections: vec![AllowedCollection {
+                        collection_addr: collection_contract.clone(),
+                        weight: 1,
+                    }],
+                    lockup_term: vec![
+                        LockupTerm {
+                            value: 10,
+                            percent: Uint128::new(30u128),
+                            early_unstake_penalty: penalty.clone(),
+                        },
+                        LockupTerm {
+                            value: 30,
+                            percent: Uint128::new(70u128),
+                            early_unstake_penalty: penalty,
+                        },
+                    ],
+                    trait_multipliers: vec![],
+                },
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(factory_contract.clone()),
+                &create_campaign_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // add reward token
+            let send_msg: Cw20ExecuteMsg = Cw20ExecuteMsg::Send {
+                contract: "contract3".to_string(),
+                amount: Uint128::from(MOCK_1000_TOKEN_AMOUNT),
+                msg: to_binary(&CampaignReceiveMsg::AddRewardToken {}).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(ADMIN.to_string()),
+                Addr::unchecked(token_contract.clone()),
+                &send_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 20 second to make active campaign
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(20),
+                height: app.block_info().height + 20,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 stake token_id 1 with lockup term 10s
+            let send_nft_msg: Cw721ExecuteMsg = Cw721ExecuteMsg::SendNft {
+                contract: "contract3".to_string(),
+                token_id: "1".to_string(),
+                msg: to_binary(&CampaignReceiveNftMsg::StakeNft { lockup_term: 10 }).unwrap(),
+            };
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked(collection_contract.clone()),
+                &send_nft_msg,
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // increase 4 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(4),
+                height: app.block_info().height + 4,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // relock into the same lockup term -> error
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ChangeLockupTerm {
+                    key: NftKey {
+                        key: 1,
+                        lockup_term: 10,
+                    },
+                    new_term: 10,
+                },
+                &[],
+            );
+            assert!(response.is_err());
+
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ChangeLockupTerm {
+                    key: NftKey {
+                        key: 1,
+                        lockup_term: 10,
+                    },
+                    new_term: 30,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // reward of token_id 1 is kept in nft
+            let nft_info: NftInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftInfo {
+                        nft_key: NftKey {
+                            key: 1,
+                            lockup_term: 30,
+                        },
+                    },
+                )
+                .unwrap();
+            assert_eq!(nft_info.pending_reward, vec![Uint128::new(12_000u128)]);
+
+            // USER_1 claim reward while token_id 1 is in staking period
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::ClaimReward {
+                    amounts: None,
+                    recipient: None,
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::zero()]);
+            assert_eq!(staker_info.reward_claimed, vec![Uint128::zero()]);
+
+            // increase 6 second
+            app.set_block(BlockInfo {
+                time: app.block_info().time.plus_seconds(6),
+                height: app.block_info().height + 6,
+                chain_id: app.block_info().chain_id,
+            });
+
+            // USER_1 un stake token_id 1 before end of lockup term
+            let response = app.execute_contract(
+                Addr::unchecked(USER_1.to_string()),
+                Addr::unchecked("contract3"),
+                &CampaignExecuteMsg::UnStakeNft {
+                    unstake_info: NftKey {
+                        key: 1,
+                        lockup_term: 30,
+                    },
+                    token_id: "1".to_string(),
+                },
+                &[],
+            );
+            assert!(response.is_ok());
+
+            // penalty is taken from reward earned in both lockup terms
+            let staker_info: StakerRewardAssetInfo = app
+                .wrap()
+                .query_wasm_smart(
+                    "contract3",
+                    &CampaignQueryMsg::NftStaked {
+                        owner: Addr::unchecked(USER_1.to_string()),
+                    },
+                )
+                .unwrap();
+            assert_eq!(staker_info.reward_debt, vec![Uint128::new(27_000u128)]);
+
+            let campaign_info: CampaignInfo = app
+                .wrap()
+                .query_wasm_smart("contract3", &CampaignQueryMsg::CampaignInfo {})
+                .unwrap();
+            assert_eq!(
+                campaign_info.reward_tokens[0].total_penalty,
+                Uint128::new(27_000u128)
+            );
+        }
+
+        //         -------------- scale weights with overflowing weight ------------------
+        // - save state version 3 of campaign with token_id 1 of weight u64::MAX
+        // - scale_weights -> error, weight of token_id 1 overflows
+        #[test]
+        fn wrong_operation_scale_weights_overflow() {
+            let mut deps = mock_dependencies();
+
+            let lockup_term = LockupTerm {
+                value: 10,
+                percent: Uint128::new(100u128),
+                early_unstake_penalty: None,
+            };
+
+            CAMPAIGN_INFO
+                .save(
+                    deps.as_mut().storage,
+                    &CampaignInfo {
+                        owner: Addr::unchecked(ADMIN),
+                        campaign_name: "campaign name".to_string(),
+                        campaign_image: "campaign name".to_string(),
+                        campaign_description: "campaign name".to_string(),
+                        limit_per_staker: 4,
+                        reward_tokens: vec![],
+                        allowed_collections: vec![AllowedCollection {
+                            collection_addr: "collection".to_string(),
+                            weight: 1,
+                        }],
+                        lockup_term: vec![lockup_term.clone()],
+                        start_time: 1000,
+                        end_time: 1100,
+                        trait_multipliers: vec![],
+                        cancelled: false,
+                    },
+                )
+                .unwrap();
+
+            NFTS.save(
+                deps.as_mut().storage,
+                (1, 10),
+                &NftInfo {
+                    key: 1,
+                    token_id: "1".to_string(),
+                    collection_addr: Addr::unchecked("collection"),
+                    weight: u64::MAX,
+                    owner: Addr::unchecked(USER_1),
+                    pending_reward: vec![],
+                    lockup_term,
+                    is_end_reward: false,
+                    start_time: 1020,
+                    time_calc: 1020,
+                    end_time: 1030,
+                },
+            )
+            .unwrap();
+
+            let response = migrations::scale_weights(deps.as_mut().storage);
+            assert!(matches!(
+                response,
+                Err(ContractError::Overflow {
+                    term: Some(10),
+                    key: Some(1),
+                    ..
+                })
+            ));
+        }
     }
 }
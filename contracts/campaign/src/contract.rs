@@ -1,29 +1,51 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response,
-    StdError, StdResult, Uint128, WasmMsg, WasmQuery,
+    from_binary, to_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Decimal256, Deps,
+    DepsMut, Env, MessageInfo, Order, QueryRequest, Response, StdError, StdResult, Storage,
+    Uint128, WasmMsg, WasmQuery,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::migrations::{migrate_state, LATEST_STATE_VERSION};
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg, ReceiveNftMsg, UpdateCampaignInfo,
+};
 use crate::state::{
-    AssetToken, CampaignInfo, Config, NftInfo, NftKey, NftStake, RewardRate, StakerRewardAssetInfo,
-    TokenInfo, CAMPAIGN_INFO, CONFIG, NFTS, NUMBER_OF_NFTS, PREVIOUS_TOTAL_REWARD, STAKERS_INFO,
-    TERM_EXPIRATION_TIMES, TERM_REWARD_RATES, TOTAL_STAKING_BY_TERM,
+    AllowedCollection, AssetToken, CampaignInfo, Config, EarlyUnstakePenalty, ExpirationTime,
+    LockupTerm, Metadata, NftInfo, NftKey, NftPendingReward, NftStake, PendingTransfer,
+    ResetPoolCursor, ResetPoolProgress, ResetPoolProgressResponse, RewardToken,
+    SimulateStakeResponse, StakerPendingRewardResponse, StakerRewardAssetInfo, StatusResponse,
+    TermResetProgress, TermRewardIndex, TokenInfo, TraitMultiplier, CAMPAIGN_INFO, CONFIG, NFTS,
+    NFT_REWARD_CHECKPOINTS, NFT_REWARD_DUST, NUMBER_OF_NFTS, PENDING_ADMIN, PENDING_OWNER,
+    RESET_POOL_PROGRESS, REWARD_RECIPIENTS, STAKERS_INFO, STATE_VERSION, TERM_EXPIRATIONS,
+    TERM_REWARD_INDEXES, WEIGHT_PRECISION,
+};
+use crate::utils::{
+    add_reward, calculate_reward, expire_nfts, simulate_stake, sub_reward, ExpiredNft,
 };
-use crate::utils::{add_reward, calculate_reward, stake_nft, sub_reward, update_reward_rate};
-use cw20::Cw20ExecuteMsg;
-use cw721::{Cw721ExecuteMsg, Cw721QueryMsg};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg};
+use cw_storage_plus::Bound;
+use cw_utils::{must_pay, nonpayable};
+use std::iter;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:campaign";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const MAX_TIME_VALID: u64 = 94608000; // 3 years
+const SECONDS_PER_YEAR: u64 = 31536000;
 const MAX_LENGTH_NAME: usize = 100;
 const MAX_LENGTH_IMAGE: usize = 500;
 const MAX_LENGTH_DESCRIPTION: usize = 500;
+const MAX_REWARD_TOKENS: usize = 5;
+const MAX_ALLOWED_COLLECTIONS: usize = 10;
+const MAX_COLLECTION_WEIGHT: u64 = 10_000;
+const MAX_TRAIT_MULTIPLIERS: usize = 20;
+const DEFAULT_RESET_POOL_LIMIT: u32 = 30;
+const MAX_RESET_POOL_LIMIT: u32 = 100;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -35,16 +57,61 @@ pub fn instantiate(
     // set version to contract
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // validate token contract address
-    match msg.reward_token_info.info.clone() {
-        TokenInfo::Token { contract_addr } => {
-            deps.api.addr_validate(&contract_addr)?;
+    // campaign must have 1 to MAX_REWARD_TOKENS reward tokens
+    if msg.reward_tokens.is_empty() || msg.reward_tokens.len() > MAX_REWARD_TOKENS {
+        return Err(ContractError::InvalidToken {});
+    }
+
+    for (i, token_info) in msg.reward_tokens.iter().enumerate() {
+        // validate token contract address
+        match token_info {
+            TokenInfo::Token { contract_addr } => {
+                deps.api.addr_validate(contract_addr)?;
+            }
+            TokenInfo::NativeToken { denom } => {
+                if denom.is_empty() {
+                    return Err(ContractError::InvalidToken {});
+                }
+            }
+        }
+
+        // not allow duplicated reward token
+        if msg.reward_tokens[..i].contains(token_info) {
+            return Err(ContractError::AlreadyExist {});
+        }
+    }
+
+    // campaign must have 1 to MAX_ALLOWED_COLLECTIONS allowed collections
+    if msg.allowed_collections.is_empty() || msg.allowed_collections.len() > MAX_ALLOWED_COLLECTIONS
+    {
+        return Err(ContractError::InvalidCollection {});
+    }
+
+    let mut allowed_collections: Vec<AllowedCollection> = vec![];
+    for collection in msg.allowed_collections.iter() {
+        // validate collection contract address, weight of collection must be greater than 0
+        // and not exceed MAX_COLLECTION_WEIGHT
+        let collection_addr = deps.api.addr_validate(&collection.collection_addr)?;
+        if collection.weight == 0 || collection.weight > MAX_COLLECTION_WEIGHT {
+            return Err(ContractError::InvalidCollection {});
         }
-        TokenInfo::NativeToken { denom: _ } => {
-            return Err(ContractError::InvalidToken {});
+
+        // not allow duplicated collection
+        if allowed_collections
+            .iter()
+            .any(|c| c.collection_addr == collection_addr.as_str())
+        {
+            return Err(ContractError::AlreadyExist {});
         }
+
+        allowed_collections.push(AllowedCollection {
+            collection_addr: collection_addr.to_string(),
+            weight: collection.weight,
+        });
     }
 
+    validate_trait_multipliers(&msg.trait_multipliers)?;
+
     // Not allow start time is greater than end time
     if msg.start_time >= msg.end_time {
         return Err(ContractError::Std(StdError::generic_err(
@@ -57,35 +124,18 @@ pub fn instantiate(
         return Err(ContractError::LimitStartDate {});
     }
 
-    // validate limit character campaign name & campaign description
-    if msg.campaign_name.len() > MAX_LENGTH_NAME {
-        return Err(ContractError::LimitCharacter {
-            max: MAX_LENGTH_NAME.to_string(),
-        });
-    }
-
-    if msg.campaign_image.len() > MAX_LENGTH_IMAGE {
-        return Err(ContractError::LimitCharacter {
-            max: MAX_LENGTH_IMAGE.to_string(),
-        });
-    }
-
-    if msg.campaign_description.len() > MAX_LENGTH_DESCRIPTION {
-        return Err(ContractError::LimitCharacter {
-            max: MAX_LENGTH_DESCRIPTION.to_string(),
-        });
-    }
-
-    let total_percent = msg.lockup_term.iter().fold(Uint128::zero(), |acc, term| {
-        acc.checked_add(term.percent).unwrap()
-    });
+    validate_campaign_metadata(
+        &msg.campaign_name,
+        &msg.campaign_image,
+        &msg.campaign_description,
+    )?;
 
-    if total_percent != Uint128::from(100u128) {
-        return Err(ContractError::InvalidFunds {});
-    }
+    validate_lockup_terms(deps.as_ref(), &msg.lockup_term)?;
 
     let config = Config {
         admin: deps.api.addr_validate(&msg.admin).unwrap(),
+        paused: false,
+        emergency: false,
     };
 
     // save config can reset pool
@@ -97,38 +147,54 @@ pub fn instantiate(
         campaign_name: msg.campaign_name.clone(),
         campaign_image: msg.campaign_image.clone(),
         campaign_description: msg.campaign_description.clone(),
-        total_reward_claimed: Uint128::zero(),
-        total_reward: Uint128::zero(),
         limit_per_staker: msg.limit_per_staker,
-        reward_token: AssetToken {
-            info: msg.reward_token_info.info.clone(),
-            amount: Uint128::zero(),
-        },
-        allowed_collection: deps.api.addr_validate(&msg.allowed_collection).unwrap(),
+        reward_tokens: msg
+            .reward_tokens
+            .iter()
+            .map(|token_info| RewardToken {
+                info: token_info.clone(),
+                amount: Uint128::zero(),
+                reward_per_second: Decimal256::zero(),
+                total_reward: Uint128::zero(),
+                total_reward_claimed: Uint128::zero(),
+                total_penalty: Uint128::zero(),
+            })
+            .collect(),
+        allowed_collections,
         lockup_term: msg.lockup_term.clone(),
-        reward_per_second: Uint128::zero(),
         start_time: msg.start_time,
         end_time: msg.end_time,
+        trait_multipliers: msg.trait_multipliers.clone(),
+        cancelled: false,
     };
 
     // save campaign info
     CAMPAIGN_INFO.save(deps.storage, &campaign)?;
 
-    // init TERM_REWARD_RATES, TOTAL_STAKING_BY_TERM, EXPIRATION_TIMES, NUMBER_OF_NFTS
+    // init TERM_REWARD_INDEXES, NUMBER_OF_NFTS
     for term in msg.lockup_term.iter() {
-        TERM_REWARD_RATES.save(deps.storage, term.value, &vec![])?;
-        TOTAL_STAKING_BY_TERM.save(deps.storage, term.value, &0u64)?;
-        TERM_EXPIRATION_TIMES.save(deps.storage, term.value, &vec![])?;
+        init_term_reward_index(deps.storage, &campaign, term.value)?;
         NUMBER_OF_NFTS.save(deps.storage, term.value, &0u64)?;
     }
 
-    PREVIOUS_TOTAL_REWARD.save(deps.storage, &Uint128::zero())?;
+    // new campaign does not need data migrations
+    STATE_VERSION.save(deps.storage, &LATEST_STATE_VERSION)?;
 
-    // we need emit the information of reward token to response
-    let reward_token_info_str = match msg.reward_token_info.info {
-        TokenInfo::Token { contract_addr } => contract_addr,
-        TokenInfo::NativeToken { denom } => denom,
-    };
+    // we need emit the information of allowed collections to response
+    let allowed_collections_str = msg
+        .allowed_collections
+        .iter()
+        .map(|collection| collection.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    // we need emit the information of reward tokens to response
+    let reward_token_info_str = msg
+        .reward_tokens
+        .iter()
+        .map(|token_info| token_info.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
 
     // emit the information of instantiated campaign
     Ok(Response::new().add_attributes([
@@ -139,13 +205,48 @@ pub fn instantiate(
         ("campaign_description", &msg.campaign_description),
         ("limit_per_staker", &msg.limit_per_staker.to_string()),
         ("reward_token_info", &reward_token_info_str),
-        ("allowed_collection", &msg.allowed_collection),
+        ("allowed_collections", &allowed_collections_str),
         ("lockup_term", &format!("{:?}", &msg.lockup_term)),
         ("start_time", &msg.start_time.to_string()),
         ("end_time", &msg.end_time.to_string()),
     ]))
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let previous = get_contract_version(deps.storage)?;
+
+    // only campaign contract can be migrated
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::CannotMigrate {
+            previous_contract: previous.contract,
+        });
+    }
+
+    // not allow downgrade contract version
+    let previous_version: Version = previous.version.parse()?;
+    let new_version: Version = CONTRACT_VERSION.parse()?;
+    if previous_version > new_version {
+        return Err(ContractError::CannotMigrateVersion {
+            previous_version: previous.version,
+            new_version: CONTRACT_VERSION.to_string(),
+        });
+    }
+
+    // run data migrations in order
+    let state_version = migrate_state(deps.storage)?;
+
+    // set new version to contract
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "migrate"),
+        ("previous_version", &previous.version),
+        ("new_version", CONTRACT_VERSION),
+        ("state_version", &state_version.to_string()),
+    ]))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -153,17 +254,61 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // cancelled campaign rejects every execute
+    if CAMPAIGN_INFO.load(deps.storage)?.cancelled {
+        return Err(ContractError::CampaignCancelled {});
+    }
+
     match msg {
-        ExecuteMsg::AddRewardToken { amount } => execute_add_reward_token(deps, env, info, amount),
+        ExecuteMsg::AddRewardToken { reward_token } => {
+            execute_add_reward_token(deps, env, info, reward_token)
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
         ExecuteMsg::StakeNfts { stake_info } => execute_stake_nft(deps, env, info, stake_info),
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_nft(deps, env, info, msg),
         ExecuteMsg::UnStakeNft {
             unstake_info,
             token_id,
         } => execute_unstake_nft(deps, env, info, unstake_info, token_id),
-        ExecuteMsg::ClaimReward { amount } => execute_claim_reward(deps, env, info, amount),
+        ExecuteMsg::UnStakeNfts { keys } => execute_unstake_nfts(deps, env, info, keys),
+        ExecuteMsg::UnStakeAll {} => execute_unstake_all(deps, env, info),
+        ExecuteMsg::ChangeLockupTerm { key, new_term } => {
+            execute_change_lockup_term(deps, env, info, key, new_term)
+        }
+        ExecuteMsg::ClaimReward { amounts, recipient } => {
+            execute_claim_reward(deps, env, info, amounts, recipient)
+        }
+        ExecuteMsg::SetRewardRecipient { recipient } => {
+            execute_set_reward_recipient(deps, env, info, recipient)
+        }
         ExecuteMsg::WithdrawReward {} => execute_withdraw_reward(deps, env, info),
-        ExecuteMsg::ResetPool {} => execute_reset_pool(deps, env, info),
-        ExecuteMsg::UpdateAdmin { admin } => execute_update_admin(deps, env, info, admin),
+        ExecuteMsg::CancelCampaign {} => execute_cancel_campaign(deps, env, info),
+        ExecuteMsg::ResetPool { limit } => execute_reset_pool(deps, env, info, limit),
+        ExecuteMsg::ProposeNewOwner { owner, expires_at } => {
+            execute_propose_new_owner(deps, env, info, owner, expires_at)
+        }
+        ExecuteMsg::AcceptOwnership {} => execute_accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipProposal {} => {
+            execute_cancel_ownership_proposal(deps, env, info)
+        }
+        ExecuteMsg::ProposeNewAdmin { admin, expires_at } => {
+            execute_propose_new_admin(deps, env, info, admin, expires_at)
+        }
+        ExecuteMsg::AcceptAdmin {} => execute_accept_admin(deps, env, info),
+        ExecuteMsg::CancelAdminProposal {} => execute_cancel_admin_proposal(deps, env, info),
+        ExecuteMsg::UpdateEndTime {
+            end_time,
+            reward_token,
+        } => execute_update_end_time(deps, env, info, end_time, reward_token),
+        ExecuteMsg::UpdateCampaign { update_campaign } => {
+            execute_update_campaign(deps, env, info, update_campaign)
+        }
+        ExecuteMsg::UpdateTraitMultipliers { trait_multipliers } => {
+            execute_update_trait_multipliers(deps, env, info, trait_multipliers)
+        }
+        ExecuteMsg::SetPaused { paused } => execute_set_paused(deps, env, info, paused),
+        ExecuteMsg::SetEmergency { emergency } => execute_set_emergency(deps, env, info, emergency),
+        ExecuteMsg::EmergencyWithdraw {} => execute_emergency_withdraw(deps, env, info),
     }
 }
 
@@ -171,64 +316,42 @@ pub fn execute_add_reward_token(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    amount: Uint128,
+    reward_token: AssetToken,
 ) -> Result<Response, ContractError> {
+    ensure_not_paused(deps.as_ref())?;
+
     // load campaign info
     let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
 
     let current_time = env.block.time.seconds();
+    let amount = reward_token.amount;
 
     // only owner can add reward token to campaign
     if campaign_info.owner != info.sender {
         return Err(ContractError::Unauthorized {});
     }
 
-    // only reward_per_second == 0 || start_time > current_time can add reward
-    if campaign_info.reward_per_second != Uint128::zero()
-        && campaign_info.start_time <= current_time
-    {
-        return Err(ContractError::InvalidTimeToAddReward {});
-    }
+    // reward token must be one of reward tokens of campaign
+    let index = campaign_info
+        .reward_tokens
+        .iter()
+        .position(|token| token.info == reward_token.info)
+        .ok_or(ContractError::InvalidToken {})?;
 
-    let mut res = Response::new();
+    let res = collect_reward_token(&env, &info, &reward_token)?;
 
-    // we need determine the reward token is native token or cw20 token
-    match campaign_info.reward_token.info.clone() {
-        TokenInfo::Token { contract_addr } => {
-            // execute cw20 transfer msg from info.sender to contract
-            res = res.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: contract_addr.to_string(),
-                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
-                    owner: info.sender.to_string(),
-                    recipient: env.contract.address.to_string(),
-                    amount,
-                })?,
-                funds: vec![],
-            }));
+    // update amount, reward_per_second token in campaign
+    deposit_reward(
+        deps.storage,
+        &mut campaign_info,
+        index,
+        amount,
+        current_time,
+    )?;
 
-            // add token info to response
-            res = res.add_attribute("reward_token_info", contract_addr);
+    // save campaign
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
 
-            // update amount, reward_per_second token in campaign
-            campaign_info.reward_token.amount = campaign_info
-                .reward_token
-                .amount
-                .checked_add(amount)
-                .unwrap();
-            campaign_info.reward_per_second = campaign_info
-                .reward_token
-                .amount
-                .checked_div(Uint128::from(
-                    campaign_info.end_time - campaign_info.start_time,
-                ))
-                .unwrap();
-            campaign_info.total_reward = campaign_info.total_reward.checked_add(amount).unwrap();
-
-            // save campaign
-            CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
-        }
-        TokenInfo::NativeToken { denom: _ } => {}
-    }
     Ok(res.add_attributes([
         ("action", "add_reward_token"),
         ("owner", campaign_info.owner.as_ref()),
@@ -236,80 +359,87 @@ pub fn execute_add_reward_token(
     ]))
 }
 
-pub fn execute_stake_nft(
+pub fn execute_receive(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    stake_info: NftStake,
+    wrapper: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
-    // load campaign info
-    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
-
-    // check invalid lockup_term
-    if !campaign_info
-        .lockup_term
-        .iter()
-        .any(|t| t.value == stake_info.lockup_term)
-    {
-        return Err(ContractError::InvalidLockupTerm {});
+    match from_binary(&wrapper.msg)? {
+        ReceiveMsg::AddRewardToken {} => {
+            execute_receive_reward_token(deps, env, info, wrapper.sender, wrapper.amount)
+        }
     }
+}
 
-    // load lockup_term in campaign info
-    let lockup_term = campaign_info
-        .lockup_term
-        .iter()
-        .find(|&term| term.value == stake_info.lockup_term)
-        .cloned()
-        .unwrap();
+pub fn execute_receive_reward_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    ensure_not_paused(deps.as_ref())?;
+
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
 
     let current_time = env.block.time.seconds();
 
-    // the reward token must be added to campaign before staking nft
-    if campaign_info.reward_token.amount == Uint128::zero() {
-        return Err(ContractError::EmptyReward {});
-    }
+    // only the contract of a cw20 reward token can send reward token to campaign
+    let index = campaign_info
+        .reward_tokens
+        .iter()
+        .position(|token| {
+            token.info
+                == TokenInfo::Token {
+                    contract_addr: info.sender.to_string(),
+                }
+        })
+        .ok_or(ContractError::InvalidToken {})?;
 
-    // only start_time < current_time && current_time < end_time && amount != 0 can stake nft
-    if campaign_info.start_time >= current_time {
-        return Err(ContractError::InvalidTimeToStakeNft {});
-    }
-    if campaign_info.end_time <= current_time {
-        return Err(ContractError::InvalidTimeToStakeNft {});
+    // only owner can add reward token to campaign
+    if campaign_info.owner != sender {
+        return Err(ContractError::Unauthorized {});
     }
 
-    // load staker_info or default if staker has not staked nft
-    let mut staker_info = STAKERS_INFO
-        .may_load(deps.storage, info.sender.clone())?
-        .unwrap_or(StakerRewardAssetInfo {
-            keys: vec![],
-            reward_debt: Uint128::zero(),
-            reward_claimed: Uint128::zero(),
-        });
+    // update amount, reward_per_second token in campaign
+    deposit_reward(
+        deps.storage,
+        &mut campaign_info,
+        index,
+        amount,
+        current_time,
+    )?;
 
-    // if limit per staker > 0 then check amount nft staked
-    // if limit_per_staker = 0, then no limit nft stake
-    if campaign_info.limit_per_staker > 0 {
-        // the length of token_ids + length nft staked should be smaller than limit per staker
-        if stake_info.token_ids.len() + staker_info.keys.len()
-            > campaign_info.limit_per_staker as usize
-        {
-            return Err(ContractError::LimitPerStake {});
-        }
-    }
+    // save campaign
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
 
-    // prepare response
-    let mut res = Response::new();
+    Ok(Response::new().add_attributes([
+        ("action", "add_reward_token"),
+        ("owner", campaign_info.owner.as_ref()),
+        ("reward_token_info", info.sender.as_ref()),
+        ("reward_token_amount", &amount.to_string()),
+    ]))
+}
+
+pub fn execute_stake_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    stake_info: NftStake,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
 
-    let mut nft_key = NUMBER_OF_NFTS.load(deps.storage, stake_info.lockup_term)?;
+    // only nft of allowed collections can be staked
+    let collection = allowed_collection(&campaign_info, &stake_info.collection_addr)?;
 
-    // load TERM_REWARD_RATES, TOTAL_STAKING_BY_TERM, TERM_EXPIRATION_TIMES
-    let term_reward_rates = TERM_REWARD_RATES.load(deps.storage, stake_info.lockup_term)?;
-    let term_expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, stake_info.lockup_term)?;
-    let total_staking_by_term = TOTAL_STAKING_BY_TERM.load(deps.storage, stake_info.lockup_term)?;
+    // prepare response
+    let mut res = Response::new();
 
     // check the owner of token_ids, all token_ids should be owned by info.sender
     for token_id in &stake_info.token_ids {
-        nft_key += 1;
         // check owner of nft
         let query_owner_msg = Cw721QueryMsg::OwnerOf {
             token_id: token_id.clone(),
@@ -318,7 +448,7 @@ pub fn execute_stake_nft(
 
         let owner_response: StdResult<cw721::OwnerOfResponse> =
             deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr: campaign_info.allowed_collection.clone().to_string(),
+                contract_addr: collection.collection_addr.clone(),
                 msg: to_binary(&query_owner_msg)?,
             }));
         match owner_response {
@@ -338,7 +468,7 @@ pub fn execute_stake_nft(
 
         // prepare message to transfer nft to contract
         let transfer_nft_msg = WasmMsg::Execute {
-            contract_addr: campaign_info.allowed_collection.clone().to_string(),
+            contract_addr: collection.collection_addr.clone(),
             msg: to_binary(&Cw721ExecuteMsg::TransferNft {
                 recipient: env.contract.address.clone().to_string(),
                 token_id: token_id.clone(),
@@ -346,176 +476,1614 @@ pub fn execute_stake_nft(
             funds: vec![],
         };
 
-        let nft_info = NftInfo {
-            key: nft_key,
-            token_id: token_id.clone(),
-            owner: info.sender.clone(),
-            pending_reward: Uint128::zero(),
-            lockup_term: lockup_term.clone(),
-            is_end_reward: false,
-            start_time: current_time,
-            time_calc: current_time,
-            end_time: (current_time + lockup_term.value),
-        };
-        // save info nft
-        NFTS.save(deps.storage, (nft_key, lockup_term.value), &nft_info)?;
-
-        let (new_term_expiration_times, new_term_reward_rates, new_total_staking_by_term) =
-            stake_nft(
-                term_expiration_times.clone(),
-                term_reward_rates.clone(),
-                total_staking_by_term,
-                nft_info.clone(),
-                current_time,
-            );
-
-        // save TERM_REWARD_RATES, TOTAL_STAKING_BY_TERM, TERM_EXPIRATION_TIMES
-        TERM_REWARD_RATES.save(deps.storage, stake_info.lockup_term, &new_term_reward_rates)?;
-        TERM_EXPIRATION_TIMES.save(
-            deps.storage,
-            stake_info.lockup_term,
-            &new_term_expiration_times,
-        )?;
-        TOTAL_STAKING_BY_TERM.save(
-            deps.storage,
-            stake_info.lockup_term,
-            &new_total_staking_by_term,
-        )?;
-
-        // save staker_info
-        staker_info.keys.push(NftKey {
-            key: nft_key,
-            lockup_term: lockup_term.value,
-        });
-
         res = res.add_message(transfer_nft_msg);
     }
 
-    // save NUMBER_OF_NFTS
-    NUMBER_OF_NFTS.save(deps.storage, stake_info.lockup_term, &nft_key)?;
-
-    // save STAKER_INFO
-    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker_info)?;
+    // save nfts staked and update reward rates of lockup term
+    stake_nfts(
+        deps,
+        &env,
+        &campaign_info,
+        &collection,
+        &info.sender,
+        &stake_info.token_ids,
+        stake_info.lockup_term,
+    )?;
 
     Ok(res.add_attributes([
         ("action", "stake_nft"),
         ("owner", info.sender.as_ref()),
-        (
-            "allowed_collection",
-            campaign_info.allowed_collection.as_ref(),
-        ),
+        ("collection_addr", &collection.collection_addr),
         ("stake_info", &format!("{:?}", &stake_info)),
     ]))
 }
 
-pub fn execute_unstake_nft(
+pub fn execute_receive_nft(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    unstake_info: NftKey,
-    _token_id: String,
+    wrapper: Cw721ReceiveMsg,
 ) -> Result<Response, ContractError> {
     // load campaign info
     let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
-    // prepare response
-    let mut res = Response::new();
-
-    if NFTS
-        .may_load(deps.storage, (unstake_info.key, unstake_info.lockup_term))?
-        .is_none()
-    {
-        return Err(ContractError::EmptyNft {
-            key: unstake_info.key,
-        });
-    }
-
-    // max time calc pending reward is campaign_info.end_time
-    let mut current_time = env.block.time.seconds();
-    if campaign_info.end_time < env.block.time.seconds() {
-        current_time = campaign_info.end_time;
-    }
-
-    // load nft info
-    let nft_info = NFTS.load(deps.storage, (unstake_info.key, unstake_info.lockup_term))?;
-
-    if nft_info.owner != info.sender {
-        return Err(ContractError::NotOwner {
-            token_id: nft_info.token_id,
-        });
-    }
 
-    // load TERM_REWARD_RATES
-    let term_reward_rates = TERM_REWARD_RATES.load(deps.storage, nft_info.lockup_term.value)?;
-    let total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, nft_info.lockup_term.value)?;
-    let expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, nft_info.lockup_term.value)?;
-
-    let (new_nft_info, _, _, _) = calculate_reward(
-        nft_info.clone(),
-        term_reward_rates,
-        expiration_times,
-        total_staking,
-        current_time,
-        campaign_info.end_time,
-        campaign_info.reward_per_second,
-    );
+    // only nft of allowed collections can be staked
+    let collection = allowed_collection(&campaign_info, info.sender.as_str())?;
 
-    // check time unstake and owner nft
-    if !new_nft_info.is_end_reward {
-        return Err(ContractError::InvalidTimeToUnStake {});
-    }
+    let staker = deps.api.addr_validate(&wrapper.sender)?;
 
-    // prepare message to transfer nft back to the owner
-    let transfer_nft_msg = WasmMsg::Execute {
-        contract_addr: campaign_info.allowed_collection.to_string(),
-        msg: to_binary(&Cw721ExecuteMsg::TransferNft {
-            recipient: nft_info.owner.to_string(),
-            token_id: nft_info.token_id.clone(),
-        })?,
-        funds: vec![],
+    let stake_info = match from_binary(&wrapper.msg)? {
+        ReceiveNftMsg::StakeNft { lockup_term } => NftStake {
+            collection_addr: collection.collection_addr.clone(),
+            token_ids: vec![wrapper.token_id],
+            lockup_term,
+        },
     };
-    res = res.add_message(transfer_nft_msg);
-
-    // update reward for staker
-    let mut staker = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
-    staker.reward_debt = add_reward(staker.reward_debt, new_nft_info.pending_reward).unwrap();
-    staker.keys.retain(|k| *k != unstake_info); // remove nft for staker
-    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker)?;
 
-    // remove nft in NFTS
-    NFTS.remove(deps.storage, (unstake_info.key, unstake_info.lockup_term));
+    // nft has been transferred to contract, save nft staked and update reward rates of lockup term
+    stake_nfts(
+        deps,
+        &env,
+        &campaign_info,
+        &collection,
+        &staker,
+        &stake_info.token_ids,
+        stake_info.lockup_term,
+    )?;
 
-    Ok(res.add_attributes([
-        ("action", "unstake_nft"),
-        ("owner", info.sender.as_ref()),
-        (
-            "allowed_collection",
-            campaign_info.allowed_collection.as_ref(),
-        ),
-        ("token_id", &nft_info.token_id),
+    Ok(Response::new().add_attributes([
+        ("action", "stake_nft"),
+        ("owner", staker.as_ref()),
+        ("collection_addr", &collection.collection_addr),
+        ("stake_info", &format!("{:?}", &stake_info)),
     ]))
 }
 
-pub fn execute_claim_reward(
+// save nfts staked by staker and add their weight to reward index of lockup term
+fn stake_nfts(
     deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    amount: Uint128,
-) -> Result<Response, ContractError> {
-    // load campaign info
-    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+    env: &Env,
+    campaign_info: &CampaignInfo,
+    collection: &AllowedCollection,
+    staker: &Addr,
+    token_ids: &[String],
+    term_value: u64,
+) -> Result<(), ContractError> {
+    ensure_not_paused(deps.as_ref())?;
 
-    // Only stakers could claim rewards in this campaign
-    if STAKERS_INFO
+    // check invalid lockup_term
+    if !campaign_info
+        .lockup_term
+        .iter()
+        .any(|t| t.value == term_value)
+    {
+        return Err(ContractError::InvalidLockupTerm {});
+    }
+
+    // load lockup_term in campaign info
+    let lockup_term = campaign_info
+        .lockup_term
+        .iter()
+        .find(|&term| term.value == term_value)
+        .cloned()
+        .unwrap();
+
+    let current_time = env.block.time.seconds();
+
+    // the reward token must be added to campaign before staking nft
+    if campaign_info
+        .reward_tokens
+        .iter()
+        .all(|token| token.amount == Uint128::zero())
+    {
+        return Err(ContractError::EmptyReward {});
+    }
+
+    // only start_time < current_time && current_time < end_time && amount != 0 can stake nft
+    if campaign_info.start_time >= current_time {
+        return Err(ContractError::InvalidTimeToStakeNft {});
+    }
+    if campaign_info.end_time <= current_time {
+        return Err(ContractError::InvalidTimeToStakeNft {});
+    }
+
+    // load staker_info or default if staker has not staked nft
+    let mut staker_info = STAKERS_INFO
+        .may_load(deps.storage, staker.clone())?
+        .unwrap_or(StakerRewardAssetInfo {
+            keys: vec![],
+            reward_debt: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
+            reward_claimed: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
+        });
+
+    // if limit per staker > 0 then check amount nft staked
+    // if limit_per_staker = 0, then no limit nft stake
+    if campaign_info.limit_per_staker > 0 {
+        // the length of token_ids + length nft staked should be smaller than limit per staker
+        if token_ids.len() + staker_info.keys.len() > campaign_info.limit_per_staker as usize {
+            return Err(ContractError::LimitPerStake {});
+        }
+    }
+
+    let mut nft_key = NUMBER_OF_NFTS.load(deps.storage, term_value)?;
+
+    // reward of staked nfts is accrued before weight of lockup term changes
+    let mut index = update_term(deps.storage, campaign_info, &lockup_term, current_time)?;
+
+    for token_id in token_ids {
+        nft_key += 1;
+
+        // reward weight of nft by its collection and traits
+        let weight = nft_weight(deps.as_ref(), campaign_info, collection, Some(token_id))?;
+
+        let nft_info = NftInfo {
+            key: nft_key,
+            token_id: token_id.clone(),
+            collection_addr: Addr::unchecked(&collection.collection_addr),
+            weight,
+            owner: staker.clone(),
+            pending_reward: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
+            lockup_term: lockup_term.clone(),
+            is_end_reward: false,
+            start_time: current_time,
+            time_calc: current_time,
+            end_time: current_time.checked_add(lockup_term.value).ok_or_else(|| {
+                ContractError::overflow("nft end time", Some(lockup_term.value), Some(nft_key))
+            })?,
+        };
+        // save info nft
+        NFTS.save(deps.storage, (nft_key, lockup_term.value), &nft_info)?;
+        enter_term(deps.storage, &mut index, &nft_info)?;
+
+        // save staker_info
+        staker_info.keys.push(NftKey {
+            key: nft_key,
+            lockup_term: lockup_term.value,
+        });
+    }
+
+    // save TERM_REWARD_INDEXES
+    TERM_REWARD_INDEXES.save(deps.storage, term_value, &index)?;
+
+    // save NUMBER_OF_NFTS
+    NUMBER_OF_NFTS.save(deps.storage, term_value, &nft_key)?;
+
+    // save STAKER_INFO
+    STAKERS_INFO.save(deps.storage, staker.clone(), &staker_info)?;
+
+    Ok(())
+}
+
+pub fn execute_unstake_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    unstake_info: NftKey,
+    _token_id: String,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // max time calc pending reward is campaign_info.end_time
+    let mut current_time = env.block.time.seconds();
+    if campaign_info.end_time < env.block.time.seconds() {
+        current_time = campaign_info.end_time;
+    }
+
+    // load nft info
+    let nft_info = load_owned_nft(deps.as_ref(), &info.sender, &unstake_info)?;
+    let collection_addr = nft_info.collection_addr.to_string();
+    let token_id = nft_info.token_id.clone();
+
+    // update reward for staker
+    let mut staker = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
+
+    // check time unstake, nft can be unstaked early if its lockup term has penalty
+    let res = remove_nft(
+        deps.storage,
+        &mut campaign_info,
+        &mut staker,
+        nft_info,
+        current_time,
+        UnstakeMode::Early,
+    )?
+    .ok_or(ContractError::InvalidTimeToUnStake {})?;
+
+    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker)?;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    Ok(res.add_attributes([
+        ("action", "unstake_nft"),
+        ("owner", info.sender.as_ref()),
+        ("collection_addr", &collection_addr),
+        ("token_id", &token_id),
+    ]))
+}
+
+pub fn execute_unstake_nfts(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    keys: Vec<NftKey>,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    if keys.is_empty() {
+        return Err(ContractError::NoNftToUnStake {});
+    }
+
+    // staker can not stake more than limit_per_staker nfts, limit_per_staker = 0 is no limit
+    if campaign_info.limit_per_staker > 0 && keys.len() as u64 > campaign_info.limit_per_staker {
+        return Err(ContractError::TooManyNfts {
+            limit: campaign_info.limit_per_staker,
+        });
+    }
+
+    // max time calc pending reward is campaign_info.end_time
+    let mut current_time = env.block.time.seconds();
+    if campaign_info.end_time < env.block.time.seconds() {
+        current_time = campaign_info.end_time;
+    }
+
+    // validate all keys before unstaking
+    let mut nfts: Vec<NftInfo> = vec![];
+    for (i, key) in keys.iter().enumerate() {
+        if keys[..i].contains(key) {
+            return Err(ContractError::AlreadyExist {});
+        }
+        nfts.push(load_owned_nft(deps.as_ref(), &info.sender, key)?);
+    }
+
+    let mut staker = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
+    let mut res = Response::new();
+    let mut unstaked_keys: Vec<String> = vec![];
+    let mut skipped_keys: Vec<String> = vec![];
+
+    // nfts in staking period are skipped, they are not unstaked early in batch
+    for (key, nft_info) in keys.iter().zip(nfts) {
+        match remove_nft(
+            deps.storage,
+            &mut campaign_info,
+            &mut staker,
+            nft_info,
+            current_time,
+            UnstakeMode::Locked,
+        )? {
+            Some(nft_res) => {
+                res = res.add_submessages(nft_res.messages);
+                unstaked_keys.push(key.to_string());
+            }
+            None => skipped_keys.push(key.to_string()),
+        }
+    }
+
+    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker)?;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    res = res.add_attributes([("action", "unstake_nfts"), ("owner", info.sender.as_ref())]);
+
+    // attribute value must not be empty
+    if !unstaked_keys.is_empty() {
+        res = res.add_attribute("unstaked_nfts", unstaked_keys.join(","));
+    }
+    if !skipped_keys.is_empty() {
+        res = res.add_attribute("skipped_nfts", skipped_keys.join(","));
+    }
+
+    Ok(res)
+}
+
+pub fn execute_unstake_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let keys = STAKERS_INFO
+        .may_load(deps.storage, info.sender.clone())?
+        .map(|staker| staker.keys)
+        .unwrap_or_default();
+
+    execute_unstake_nfts(deps, env, info, keys)
+}
+
+// load nft which is staked by owner
+fn load_owned_nft(deps: Deps, owner: &Addr, nft_key: &NftKey) -> Result<NftInfo, ContractError> {
+    let nft_info = NFTS
+        .may_load(deps.storage, (nft_key.key, nft_key.lockup_term))?
+        .ok_or(ContractError::EmptyNft { key: nft_key.key })?;
+
+    if nft_info.owner != *owner {
+        return Err(ContractError::NotOwner {
+            token_id: nft_info.token_id,
+        });
+    }
+
+    Ok(nft_info)
+}
+
+// how nft in staking period is handled when it is unstaked
+enum UnstakeMode {
+    // nft in staking period is not unstaked
+    Locked,
+    // nft in staking period is unstaked with penalty of its lockup term
+    Early,
+    // nft is always unstaked, pending reward of nft in staking period is forfeited
+    Emergency,
+}
+
+// calculate reward of nft, move it to reward debt of staker and remove nft from campaign
+// return None if nft is in staking period and it can not be unstaked by mode
+fn remove_nft(
+    storage: &mut dyn Storage,
+    campaign_info: &mut CampaignInfo,
+    staker: &mut StakerRewardAssetInfo,
+    nft_info: NftInfo,
+    current_time: u64,
+    mode: UnstakeMode,
+) -> Result<Option<Response>, ContractError> {
+    let mut res = Response::new();
+
+    let mut index = update_term(storage, campaign_info, &nft_info.lockup_term, current_time)?;
+    let nft_key = (nft_info.key, nft_info.lockup_term.value);
+    let mut new_nft_info = settle_nft(storage, campaign_info, &index, nft_key, current_time)?;
+
+    // nft in staking period can only be unstaked early if its lockup term has penalty
+    let penalty = match (mode, &new_nft_info.lockup_term.early_unstake_penalty) {
+        _ if new_nft_info.is_end_reward => None,
+        (UnstakeMode::Emergency, _) => Some(EarlyUnstakePenalty {
+            percent: Uint128::from(100u128),
+            decay: false,
+            recipient: None,
+        }),
+        (UnstakeMode::Early, Some(penalty)) => Some(penalty.clone()),
+        _ => return Ok(None),
+    };
+
+    // remove weight of nft from lockup term from current time
+    leave_term(storage, &mut index, &new_nft_info)?;
+    TERM_REWARD_INDEXES.save(storage, new_nft_info.lockup_term.value, &index)?;
+
+    if let Some(penalty) = penalty {
+        let (term, key) = (Some(new_nft_info.lockup_term.value), Some(new_nft_info.key));
+        let overflow = || ContractError::overflow("early unstake penalty", term, key);
+
+        // penalty = pending reward * percent / 100 (* remaining time / lockup term if decay)
+        let (numerator, denominator) = if penalty.decay {
+            (
+                penalty
+                    .percent
+                    .checked_mul(Uint128::from(new_nft_info.end_time - current_time))
+                    .map_err(|_| overflow())?,
+                Uint128::from(100u128)
+                    .checked_mul(Uint128::from(new_nft_info.lockup_term.value))
+                    .map_err(|_| overflow())?,
+            )
+        } else {
+            (penalty.percent, Uint128::from(100u128))
+        };
+
+        for (reward_token, pending_reward) in campaign_info
+            .reward_tokens
+            .iter_mut()
+            .zip(new_nft_info.pending_reward.iter_mut())
+        {
+            let penalty_amount = pending_reward
+                .checked_multiply_ratio(numerator, denominator)
+                .map_err(|_| overflow())?;
+            if penalty_amount == Uint128::zero() {
+                continue;
+            }
+
+            *pending_reward = sub_reward(*pending_reward, penalty_amount)
+                .map_err(|_| ContractError::underflow("early unstake penalty", term, key))?;
+            reward_token.total_penalty =
+                add_reward(reward_token.total_penalty, penalty_amount).map_err(|_| overflow())?;
+
+            // forfeited reward is sent to recipient, otherwise it is kept in pool
+            if let Some(recipient) = &penalty.recipient {
+                res = res.add_message(transfer_reward_msg(
+                    &reward_token.info,
+                    &Addr::unchecked(recipient),
+                    penalty_amount,
+                )?);
+                reward_token.amount = sub_reward(reward_token.amount, penalty_amount)
+                    .map_err(|_| ContractError::underflow("send penalty", term, key))?;
+            }
+
+            res = res.add_attributes([
+                ("reward_token_info", reward_token.info.to_string()),
+                ("penalty_amount", penalty_amount.to_string()),
+            ]);
+        }
+    }
+
+    // prepare message to transfer nft back to the owner
+    let transfer_nft_msg = WasmMsg::Execute {
+        contract_addr: new_nft_info.collection_addr.to_string(),
+        msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: new_nft_info.owner.to_string(),
+            token_id: new_nft_info.token_id.clone(),
+        })?,
+        funds: vec![],
+    };
+    res = res.add_message(transfer_nft_msg);
+
+    // update reward for staker
+    for (reward_debt, pending_reward) in staker
+        .reward_debt
+        .iter_mut()
+        .zip(new_nft_info.pending_reward.iter())
+    {
+        *reward_debt = add_reward(*reward_debt, *pending_reward).map_err(|_| {
+            ContractError::overflow(
+                "unstake nft",
+                Some(new_nft_info.lockup_term.value),
+                Some(new_nft_info.key),
+            )
+        })?;
+    }
+    let nft_key = NftKey {
+        key: new_nft_info.key,
+        lockup_term: new_nft_info.lockup_term.value,
+    };
+    staker.keys.retain(|k| *k != nft_key); // remove nft for staker
+
+    // remove nft in NFTS
+    NFTS.remove(storage, (nft_key.key, nft_key.lockup_term));
+
+    Ok(Some(res))
+}
+
+pub fn execute_change_lockup_term(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    key: NftKey,
+    new_term: u64,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    let current_time = env.block.time.seconds();
+
+    // nft can only be moved to other lockup term while campaign is active
+    if campaign_info.start_time >= current_time || campaign_info.end_time <= current_time {
+        return Err(ContractError::InvalidTimeToStakeNft {});
+    }
+
+    // load nft info
+    let nft_info = load_owned_nft(deps.as_ref(), &info.sender, &key)?;
+
+    // new lockup term must exist and must not be shorter than current lockup term
+    let lockup_term = campaign_info
+        .lockup_term
+        .iter()
+        .find(|term| term.value == new_term)
+        .cloned()
+        .ok_or(ContractError::InvalidLockupTerm {})?;
+    if lockup_term.value <= nft_info.lockup_term.value {
+        return Err(ContractError::InvalidLockupTerm {});
+    }
+
+    // settle reward of nft in current lockup term
+    let mut index = update_term(
+        deps.storage,
+        &campaign_info,
+        &nft_info.lockup_term,
+        current_time,
+    )?;
+    let new_nft_info = settle_nft(
+        deps.storage,
+        &campaign_info,
+        &index,
+        (key.key, key.lockup_term),
+        current_time,
+    )?;
+
+    leave_term(deps.storage, &mut index, &new_nft_info)?;
+    TERM_REWARD_INDEXES.save(deps.storage, key.lockup_term, &index)?;
+    NFTS.remove(deps.storage, (key.key, key.lockup_term));
+
+    // pending reward in nft is transferred to staker, except reward of nft in staking period
+    // with penalty, which is kept in nft so penalty of new lockup term still applies to it
+    let mut staker = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
+    let pending_reward = if !new_nft_info.is_end_reward
+        && new_nft_info.lockup_term.early_unstake_penalty.is_some()
+    {
+        new_nft_info.pending_reward.clone()
+    } else {
+        for (reward_debt, pending_reward) in staker
+            .reward_debt
+            .iter_mut()
+            .zip(new_nft_info.pending_reward.iter())
+        {
+            *reward_debt = add_reward(*reward_debt, *pending_reward).map_err(|_| {
+                ContractError::overflow(
+                    "change lockup term",
+                    Some(new_nft_info.lockup_term.value),
+                    Some(new_nft_info.key),
+                )
+            })?;
+        }
+        vec![Uint128::zero(); campaign_info.reward_tokens.len()]
+    };
+
+    // re-enter nft in new lockup term with new key
+    let nft_key = NUMBER_OF_NFTS.load(deps.storage, lockup_term.value)? + 1;
+    let nft_info = NftInfo {
+        key: nft_key,
+        pending_reward,
+        lockup_term: lockup_term.clone(),
+        is_end_reward: false,
+        start_time: current_time,
+        time_calc: current_time,
+        end_time: current_time.checked_add(lockup_term.value).ok_or_else(|| {
+            ContractError::overflow("nft end time", Some(lockup_term.value), Some(nft_key))
+        })?,
+        ..new_nft_info
+    };
+    NFTS.save(deps.storage, (nft_key, lockup_term.value), &nft_info)?;
+    NUMBER_OF_NFTS.save(deps.storage, lockup_term.value, &nft_key)?;
+
+    let mut index = update_term(deps.storage, &campaign_info, &lockup_term, current_time)?;
+    enter_term(deps.storage, &mut index, &nft_info)?;
+    TERM_REWARD_INDEXES.save(deps.storage, lockup_term.value, &index)?;
+
+    let new_key = NftKey {
+        key: nft_key,
+        lockup_term: lockup_term.value,
+    };
+    for staker_key in staker.keys.iter_mut() {
+        if *staker_key == key {
+            *staker_key = new_key.clone();
+        }
+    }
+    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "change_lockup_term"),
+        ("owner", info.sender.as_ref()),
+        ("collection_addr", nft_info.collection_addr.as_ref()),
+        ("token_id", &nft_info.token_id),
+        ("old_nft_key", &key.to_string()),
+        ("new_nft_key", &new_key.to_string()),
+    ]))
+}
+
+pub fn execute_claim_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amounts: Option<Vec<AssetToken>>,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_not_paused(deps.as_ref())?;
+
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // Only stakers could claim rewards in this campaign
+    if STAKERS_INFO
         .may_load(deps.storage, info.sender.clone())?
         .is_none()
     {
         return Err(ContractError::InvalidClaim {});
     }
 
-    // load staker_info
-    let mut staker_info = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
+    // reward is sent to recipient in message, then stored reward recipient, then staker
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => REWARD_RECIPIENTS
+            .may_load(deps.storage, info.sender.clone())?
+            .unwrap_or_else(|| info.sender.clone()),
+    };
+
+    // load staker_info
+    let mut staker_info = STAKERS_INFO.load(deps.storage, info.sender.clone())?;
+
+    // max time calc pending reward is campaign_info.end_time
+    let mut current_time = env.block.time.seconds();
+    if campaign_info.end_time < env.block.time.seconds() {
+        current_time = campaign_info.end_time;
+    }
+
+    // transfer pending reward in nft to staker
+    for key in staker_info.keys.iter() {
+        let lockup_term = NFTS
+            .load(deps.storage, (key.key, key.lockup_term))?
+            .lockup_term;
+
+        // nft may be settled by expiration while reward of lockup term is updated
+        let index = update_term(deps.storage, &campaign_info, &lockup_term, current_time)?;
+        let mut new_nft_info = settle_nft(
+            deps.storage,
+            &campaign_info,
+            &index,
+            (key.key, key.lockup_term),
+            current_time,
+        )?;
+
+        // reward of nft in staking period with penalty is kept in nft until it is unstaked
+        if !new_nft_info.is_end_reward && new_nft_info.lockup_term.early_unstake_penalty.is_some() {
+            continue;
+        }
+
+        // pending reward in nft are transferred to staker
+        for (reward_debt, pending_reward) in staker_info
+            .reward_debt
+            .iter_mut()
+            .zip(new_nft_info.pending_reward.iter_mut())
+        {
+            *reward_debt = add_reward(*reward_debt, *pending_reward).map_err(|_| {
+                ContractError::overflow("claim reward", Some(key.lockup_term), Some(key.key))
+            })?;
+            *pending_reward = Uint128::zero();
+        }
+        NFTS.save(deps.storage, (key.key, key.lockup_term), &new_nft_info)?;
+    }
+
+    // claim all reward of staker if amounts is not specified
+    let claim_amounts = match amounts {
+        Some(amounts) => {
+            let mut claim_amounts = vec![Uint128::zero(); campaign_info.reward_tokens.len()];
+            for asset in amounts {
+                // reward token must be one of reward tokens of campaign
+                let index = campaign_info
+                    .reward_tokens
+                    .iter()
+                    .position(|token| token.info == asset.info)
+                    .ok_or(ContractError::InvalidToken {})?;
+                claim_amounts[index] = add_reward(claim_amounts[index], asset.amount)
+                    .map_err(|_| ContractError::overflow("claim amount", None, None))?;
+            }
+            claim_amounts
+        }
+        None => staker_info.reward_debt.clone(),
+    };
+
+    let mut res = Response::new();
+
+    for (index, amount) in claim_amounts.into_iter().enumerate() {
+        // amount reward claim must be less than or equal reward in staker
+        if amount > staker_info.reward_debt[index] {
+            return Err(ContractError::InsufficientBalance {});
+        }
+
+        if amount == Uint128::zero() {
+            continue;
+        }
+
+        let reward_token = &mut campaign_info.reward_tokens[index];
+
+        // transfer reward token to recipient
+        res = res
+            .add_message(transfer_reward_msg(&reward_token.info, &recipient, amount)?)
+            .add_attributes([
+                ("reward_token_info", reward_token.info.to_string()),
+                ("reward_claim_amount", amount.to_string()),
+            ]);
+
+        // update staker info
+        staker_info.reward_claimed[index] =
+            add_reward(staker_info.reward_claimed[index], amount)
+                .map_err(|_| ContractError::overflow("staker reward claimed", None, None))?;
+        staker_info.reward_debt[index] = sub_reward(staker_info.reward_debt[index], amount)
+            .map_err(|_| ContractError::underflow("staker reward debt", None, None))?;
+
+        // update reward total and reward claimed for campaign
+        reward_token.amount = sub_reward(reward_token.amount, amount)
+            .map_err(|_| ContractError::underflow("claim reward amount", None, None))?;
+        reward_token.total_reward_claimed =
+            add_reward(reward_token.total_reward_claimed, amount)
+                .map_err(|_| ContractError::overflow("total reward claimed", None, None))?;
+    }
+
+    STAKERS_INFO.save(deps.storage, info.sender, &staker_info)?;
+
+    // save campaign info
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    Ok(res.add_attributes([
+        ("action", "claim_reward"),
+        ("owner", campaign_info.owner.as_ref()),
+        ("recipient", recipient.as_ref()),
+    ]))
+}
+
+pub fn execute_set_reward_recipient(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut res = Response::new().add_attributes([
+        ("action", "set_reward_recipient"),
+        ("staker", info.sender.as_ref()),
+    ]);
+
+    match recipient {
+        Some(recipient) => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            REWARD_RECIPIENTS.save(deps.storage, info.sender, &recipient)?;
+            res = res.add_attribute("recipient", recipient);
+        }
+        None => REWARD_RECIPIENTS.remove(deps.storage, info.sender),
+    }
+
+    Ok(res)
+}
+
+pub fn execute_withdraw_reward(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // permission check
+    if info.sender != campaign_info.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // campaing must be ended then withdraw remaining reward
+    if campaign_info.end_time > env.block.time.seconds() {
+        return Err(ContractError::InvalidTimeToWithdrawReward {});
+    }
+
+    // nfts expired until now are settled, so their dust is withdrawn as well
+    checkpoint_pool(deps.storage, &campaign_info, env.block.time.seconds())?;
+
+    // total pending reward of each reward token at the end of campaign
+    let total_pending_reward =
+        calc_total_pending_reward(deps.storage, &campaign_info, campaign_info.end_time)?;
+
+    let mut res = Response::new();
+
+    for (reward_token, total_pending_reward) in campaign_info
+        .reward_tokens
+        .iter_mut()
+        .zip(total_pending_reward)
+    {
+        // reward remaining = current total reward - total pending reward
+        let withdraw_reward = reward_token
+            .amount
+            .checked_sub(total_pending_reward)
+            .map_err(|_| ContractError::underflow("withdraw reward", None, None))?;
+
+        if withdraw_reward == Uint128::zero() {
+            continue;
+        }
+
+        // transfer remaining reward token to owner
+        res = res
+            .add_message(transfer_reward_msg(
+                &reward_token.info,
+                &info.sender,
+                withdraw_reward,
+            )?)
+            .add_attributes([
+                ("reward_token_info", reward_token.info.to_string()),
+                ("withdraw_reward_amount", withdraw_reward.to_string()),
+            ]);
+
+        // update reward total and reward claimed for campaign
+        reward_token.amount = sub_reward(reward_token.amount, withdraw_reward)
+            .map_err(|_| ContractError::underflow("withdraw reward amount", None, None))?;
+    }
+
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    Ok(res.add_attributes([
+        ("action", "withdraw_reward"),
+        ("owner", campaign_info.owner.as_ref()),
+    ]))
+}
+
+pub fn execute_cancel_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // permission check
+    if info.sender != campaign_info.owner && info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // campaign can only be cancelled before start time or while nobody has staked
+    let has_staker = STAKERS_INFO
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if campaign_info.start_time <= env.block.time.seconds() && has_staker {
+        return Err(ContractError::InvalidTimeToCancel {});
+    }
+
+    let mut res = Response::new();
+
+    for reward_token in campaign_info.reward_tokens.iter_mut() {
+        // refund all reward token to owner
+        if reward_token.amount != Uint128::zero() {
+            res = res
+                .add_message(transfer_reward_msg(
+                    &reward_token.info,
+                    &campaign_info.owner,
+                    reward_token.amount,
+                )?)
+                .add_attributes([
+                    ("reward_token_info", reward_token.info.to_string()),
+                    ("refund_amount", reward_token.amount.to_string()),
+                ]);
+        }
+
+        reward_token.amount = Uint128::zero();
+        reward_token.reward_per_second = Decimal256::zero();
+    }
+
+    campaign_info.cancelled = true;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    Ok(res.add_attributes([
+        ("action", "cancel_campaign"),
+        ("sender", info.sender.as_ref()),
+        ("owner", campaign_info.owner.as_ref()),
+    ]))
+}
+
+pub fn execute_reset_pool(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    // load campaign info
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // max time calc pending reward is campaign_info.end_time
+    let mut current_time = env.block.time.seconds();
+    if campaign_info.end_time < env.block.time.seconds() {
+        current_time = campaign_info.end_time;
+    }
+
+    let limit = limit
+        .unwrap_or(DEFAULT_RESET_POOL_LIMIT)
+        .clamp(1, MAX_RESET_POOL_LIMIT) as usize;
+
+    // continue reset pool in progress, otherwise reset lockup terms until current time
+    let mut progress = RESET_POOL_PROGRESS
+        .may_load(deps.storage)?
+        .unwrap_or_else(|| ResetPoolProgress {
+            reset_time: current_time,
+            terms: campaign_info
+                .lockup_term
+                .iter()
+                .map(|term| ResetPoolCursor {
+                    term_value: term.value,
+                    settled: 0,
+                })
+                .collect(),
+        });
+
+    let mut remaining = limit;
+    let mut completed = true;
+    for cursor in progress.terms.iter_mut() {
+        // lockup term may be removed by update campaign before it starts
+        let Some(term) = campaign_info
+            .lockup_term
+            .iter()
+            .find(|term| term.value == cursor.term_value)
+        else {
+            continue;
+        };
+
+        let (settled, term_completed) = update_term_bounded(
+            deps.storage,
+            &campaign_info,
+            term,
+            progress.reset_time,
+            remaining,
+        )?;
+        cursor.settled = cursor
+            .settled
+            .checked_add(settled)
+            .ok_or_else(|| ContractError::overflow("reset pool settled", Some(term.value), None))?;
+        remaining -= settled as usize;
+
+        if !term_completed {
+            completed = false;
+            break;
+        }
+    }
+
+    if completed {
+        RESET_POOL_PROGRESS.remove(deps.storage);
+    } else {
+        RESET_POOL_PROGRESS.save(deps.storage, &progress)?;
+    }
+
+    Ok(Response::new().add_attributes([
+        ("action", "reset_pool"),
+        ("admin", config.admin.as_ref()),
+        ("reset_time", &progress.reset_time.to_string()),
+        ("settled", &(limit - remaining).to_string()),
+        ("completed", &completed.to_string()),
+    ]))
+}
+
+// accrue reward of all lockup terms to current_time and settle nfts expired until current_time
+fn checkpoint_pool(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    current_time: u64,
+) -> Result<(), ContractError> {
+    for term in campaign_info.lockup_term.iter() {
+        update_term(storage, campaign_info, term, current_time)?;
+    }
+
+    Ok(())
+}
+
+// reward index of lockup term before any nft is staked
+fn init_term_reward_index(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    term_value: u64,
+) -> StdResult<()> {
+    TERM_REWARD_INDEXES.save(
+        storage,
+        term_value,
+        &TermRewardIndex {
+            total_weight: 0,
+            last_update: campaign_info.start_time,
+            reward_per_weight: vec![Decimal256::zero(); campaign_info.reward_tokens.len()],
+            total_reward: vec![Decimal256::zero(); campaign_info.reward_tokens.len()],
+            total_dust: vec![Decimal256::zero(); campaign_info.reward_tokens.len()],
+        },
+    )
+}
+
+// calculate reward index of lockup term at current_time without saving, return it with
+// key and reward per weight at end time of nfts expired until current_time
+fn accrue_term(
+    storage: &dyn Storage,
+    campaign_info: &CampaignInfo,
+    term: &LockupTerm,
+    current_time: u64,
+) -> Result<(TermRewardIndex, Vec<ExpiredNft>), ContractError> {
+    let expirations = load_expirations(storage, term.value, Some(current_time), None)?;
+
+    accrue_expirations(storage, campaign_info, term, &expirations, current_time)
+}
+
+// calculate reward index of lockup term at current_time without saving, nfts in expirations
+// stop earning reward at their end time
+fn accrue_expirations(
+    storage: &dyn Storage,
+    campaign_info: &CampaignInfo,
+    term: &LockupTerm,
+    expirations: &[ExpirationTime],
+    current_time: u64,
+) -> Result<(TermRewardIndex, Vec<ExpiredNft>), ContractError> {
+    let mut index = TERM_REWARD_INDEXES.load(storage, term.value)?;
+
+    let expired = expire_nfts(
+        &mut index,
+        expirations,
+        current_time,
+        campaign_info.end_time,
+        term,
+        &reward_per_second(campaign_info),
+    )?;
+
+    Ok((index, expired))
+}
+
+// expirations of lockup term in order of end time, only until end_time and at most limit
+// if they are given
+fn load_expirations(
+    storage: &dyn Storage,
+    term_value: u64,
+    end_time: Option<u64>,
+    limit: Option<usize>,
+) -> StdResult<Vec<ExpirationTime>> {
+    let max = end_time.map(|end_time| Bound::inclusive((end_time, u64::MAX)));
+
+    TERM_EXPIRATIONS
+        .sub_prefix(term_value)
+        .range(storage, None, max, Order::Ascending)
+        .map(|item| {
+            item.map(|((end_time, key), weight)| ExpirationTime {
+                end_time,
+                key,
+                weight,
+            })
+        })
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+// accrue reward of lockup term to current_time, nfts expired until current_time are settled
+// at their end time and their weight is removed from lockup term
+fn update_term(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    term: &LockupTerm,
+    current_time: u64,
+) -> Result<TermRewardIndex, ContractError> {
+    let expirations = load_expirations(storage, term.value, Some(current_time), None)?;
+
+    settle_expirations(storage, campaign_info, term, &expirations, current_time)
+}
+
+// accrue reward of lockup term to current_time, at most limit nfts expired until current_time
+// are settled, otherwise reward is accrued until end time of the last settled nft. Return
+// number of settled nfts and whether lockup term is updated to current_time
+fn update_term_bounded(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    term: &LockupTerm,
+    current_time: u64,
+    limit: usize,
+) -> Result<(u64, bool), ContractError> {
+    let mut expirations =
+        load_expirations(storage, term.value, Some(current_time), Some(limit + 1))?;
+
+    if expirations.len() <= limit {
+        settle_expirations(storage, campaign_info, term, &expirations, current_time)?;
+        return Ok((expirations.len() as u64, true));
+    }
+
+    expirations.truncate(limit);
+    if let Some(last_expiration) = expirations.last() {
+        let end_time = last_expiration.end_time;
+        settle_expirations(storage, campaign_info, term, &expirations, end_time)?;
+    }
+
+    Ok((limit as u64, false))
+}
+
+// save reward index of lockup term at current_time, nfts in expirations are settled at their
+// end time and their weight is removed from lockup term
+fn settle_expirations(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    term: &LockupTerm,
+    expirations: &[ExpirationTime],
+    current_time: u64,
+) -> Result<TermRewardIndex, ContractError> {
+    let (mut index, expired) =
+        accrue_expirations(storage, campaign_info, term, expirations, current_time)?;
+
+    for (key, reward_per_weight) in expired {
+        let mut nft_info = NFTS.load(storage, (key, term.value))?;
+        let mut checkpoint = NFT_REWARD_CHECKPOINTS.load(storage, (key, term.value))?;
+        let mut dust = load_nft_dust(storage, (key, term.value), checkpoint.len())?;
+        let end_time = nft_info.end_time;
+
+        calculate_reward(
+            &mut nft_info,
+            &mut checkpoint,
+            &mut dust,
+            &reward_per_weight,
+            end_time,
+            campaign_info.end_time,
+        )?;
+
+        NFTS.save(storage, (key, term.value), &nft_info)?;
+        NFT_REWARD_CHECKPOINTS.save(storage, (key, term.value), &checkpoint)?;
+        TERM_EXPIRATIONS.remove(storage, (term.value, end_time, key));
+
+        // expired nft earns no more reward, its dust is never paid
+        drop_nft_dust(storage, &mut index, (key, term.value), dust)?;
+    }
+
+    TERM_REWARD_INDEXES.save(storage, term.value, &index)?;
+
+    Ok(index)
+}
+
+// calculate and save reward of nft at current_time, reward index of its lockup term must be
+// updated to current_time
+fn settle_nft(
+    storage: &mut dyn Storage,
+    campaign_info: &CampaignInfo,
+    index: &TermRewardIndex,
+    nft_key: (u64, u64),
+    current_time: u64,
+) -> Result<NftInfo, ContractError> {
+    let mut nft_info = NFTS.load(storage, nft_key)?;
+    let mut checkpoint = NFT_REWARD_CHECKPOINTS.load(storage, nft_key)?;
+    let mut dust = load_nft_dust(storage, nft_key, checkpoint.len())?;
+
+    calculate_reward(
+        &mut nft_info,
+        &mut checkpoint,
+        &mut dust,
+        &index.reward_per_weight,
+        current_time,
+        campaign_info.end_time,
+    )?;
+
+    NFTS.save(storage, nft_key, &nft_info)?;
+    NFT_REWARD_CHECKPOINTS.save(storage, nft_key, &checkpoint)?;
+    NFT_REWARD_DUST.save(storage, nft_key, &dust)?;
+
+    Ok(nft_info)
+}
+
+// nft starts earning reward of its lockup term from current reward per weight
+fn enter_term(
+    storage: &mut dyn Storage,
+    index: &mut TermRewardIndex,
+    nft_info: &NftInfo,
+) -> Result<(), ContractError> {
+    let term_value = nft_info.lockup_term.value;
+
+    NFT_REWARD_CHECKPOINTS.save(
+        storage,
+        (nft_info.key, term_value),
+        &index.reward_per_weight,
+    )?;
+    TERM_EXPIRATIONS.save(
+        storage,
+        (term_value, nft_info.end_time, nft_info.key),
+        &nft_info.weight,
+    )?;
+    index.total_weight = index
+        .total_weight
+        .checked_add(nft_info.weight)
+        .ok_or_else(|| {
+            ContractError::overflow("enter lockup term", Some(term_value), Some(nft_info.key))
+        })?;
+
+    Ok(())
+}
+
+// nft stops earning reward of its lockup term, its reward must be settled before
+fn leave_term(
+    storage: &mut dyn Storage,
+    index: &mut TermRewardIndex,
+    nft_info: &NftInfo,
+) -> Result<(), ContractError> {
+    let term_value = nft_info.lockup_term.value;
+
+    // weight of expired nft is already removed from lockup term
+    let expiration_key = (term_value, nft_info.end_time, nft_info.key);
+    if let Some(weight) = TERM_EXPIRATIONS.may_load(storage, expiration_key)? {
+        TERM_EXPIRATIONS.remove(storage, expiration_key);
+        index.total_weight = index.total_weight.checked_sub(weight).ok_or_else(|| {
+            ContractError::underflow("leave lockup term", Some(term_value), Some(nft_info.key))
+        })?;
+    }
+    NFT_REWARD_CHECKPOINTS.remove(storage, (nft_info.key, term_value));
+
+    let dust = load_nft_dust(
+        storage,
+        (nft_info.key, term_value),
+        index.total_reward.len(),
+    )?;
+    drop_nft_dust(storage, index, (nft_info.key, term_value), dust)?;
+
+    Ok(())
+}
+
+// fraction of reward earned by nft which is not in its pending reward yet
+fn load_nft_dust(
+    storage: &dyn Storage,
+    nft_key: (u64, u64),
+    len: usize,
+) -> StdResult<Vec<Decimal256>> {
+    Ok(NFT_REWARD_DUST
+        .may_load(storage, nft_key)?
+        .unwrap_or_else(|| vec![Decimal256::zero(); len]))
+}
+
+// dust of nft which earns no more reward is moved to lockup term, so owner can withdraw it
+fn drop_nft_dust(
+    storage: &mut dyn Storage,
+    index: &mut TermRewardIndex,
+    nft_key: (u64, u64),
+    dust: Vec<Decimal256>,
+) -> Result<(), ContractError> {
+    index.total_dust.resize(dust.len(), Decimal256::zero());
+    for (total_dust, dust) in index.total_dust.iter_mut().zip(dust) {
+        *total_dust = total_dust.checked_add(dust).map_err(|_| {
+            ContractError::overflow("drop nft dust", Some(nft_key.1), Some(nft_key.0))
+        })?;
+    }
+    NFT_REWARD_DUST.remove(storage, nft_key);
+
+    Ok(())
+}
+
+// calculate reward of nft at current_time without saving, index and expired nfts of its
+// lockup term are calculated by accrue_term
+fn query_nft_reward(
+    storage: &dyn Storage,
+    campaign_info: &CampaignInfo,
+    index: &TermRewardIndex,
+    expired: &[ExpiredNft],
+    mut nft_info: NftInfo,
+    current_time: u64,
+) -> Result<NftInfo, ContractError> {
+    let nft_key = (nft_info.key, nft_info.lockup_term.value);
+    let mut checkpoint = NFT_REWARD_CHECKPOINTS.load(storage, nft_key)?;
+    let mut dust = load_nft_dust(storage, nft_key, checkpoint.len())?;
+
+    // expired nft earns reward until its end time
+    let (reward_per_weight, time) = expired.iter().find(|(key, _)| *key == nft_info.key).map_or(
+        (&index.reward_per_weight, current_time),
+        |(_, reward_per_weight)| (reward_per_weight, nft_info.end_time),
+    );
+
+    calculate_reward(
+        &mut nft_info,
+        &mut checkpoint,
+        &mut dust,
+        reward_per_weight,
+        time,
+        campaign_info.end_time,
+    )?;
+
+    Ok(nft_info)
+}
+
+pub fn execute_propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    expires_at: Option<u64>,
+) -> Result<Response, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // permission check
+    if info.sender != campaign_info.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pending_owner = new_pending_transfer(deps.as_ref(), &env, &owner, expires_at)?;
+    PENDING_OWNER.save(deps.storage, &pending_owner)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "propose_new_owner"),
+        ("owner", campaign_info.owner.as_ref()),
+        ("pending_owner", pending_owner.new_address.as_ref()),
+    ]))
+}
+
+pub fn execute_accept_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    let pending_owner = PENDING_OWNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    check_pending_transfer(&env, &info, &pending_owner)?;
+
+    let previous_owner = campaign_info.owner;
+    campaign_info.owner = pending_owner.new_address;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+    PENDING_OWNER.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        ("action", "accept_ownership"),
+        ("previous_owner", previous_owner.as_ref()),
+        ("owner", campaign_info.owner.as_ref()),
+    ]))
+}
+
+pub fn execute_cancel_ownership_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    // permission check
+    if info.sender != campaign_info.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if PENDING_OWNER.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingTransfer {});
+    }
+    PENDING_OWNER.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        ("action", "cancel_ownership_proposal"),
+        ("owner", campaign_info.owner.as_ref()),
+    ]))
+}
+
+pub fn execute_propose_new_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    admin: String,
+    expires_at: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pending_admin = new_pending_transfer(deps.as_ref(), &env, &admin, expires_at)?;
+    PENDING_ADMIN.save(deps.storage, &pending_admin)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "propose_new_admin"),
+        ("admin", config.admin.as_ref()),
+        ("pending_admin", pending_admin.new_address.as_ref()),
+    ]))
+}
+
+pub fn execute_accept_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    let pending_admin = PENDING_ADMIN
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    check_pending_transfer(&env, &info, &pending_admin)?;
+
+    let previous_admin = config.admin;
+    config.admin = pending_admin.new_address;
+    CONFIG.save(deps.storage, &config)?;
+    PENDING_ADMIN.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        ("action", "accept_admin"),
+        ("previous_admin", previous_admin.as_ref()),
+        ("admin", config.admin.as_ref()),
+    ]))
+}
+
+pub fn execute_cancel_admin_proposal(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if PENDING_ADMIN.may_load(deps.storage)?.is_none() {
+        return Err(ContractError::NoPendingTransfer {});
+    }
+    PENDING_ADMIN.remove(deps.storage);
+
+    Ok(Response::new().add_attributes([
+        ("action", "cancel_admin_proposal"),
+        ("admin", config.admin.as_ref()),
+    ]))
+}
+
+// proposed address must be valid, expiration must be in the future
+fn new_pending_transfer(
+    deps: Deps,
+    env: &Env,
+    new_address: &str,
+    expires_at: Option<u64>,
+) -> Result<PendingTransfer, ContractError> {
+    if let Some(expires_at) = expires_at {
+        if expires_at <= env.block.time.seconds() {
+            return Err(ContractError::TransferExpired {});
+        }
+    }
 
-    let mut pending_reward_staker: Uint128 = Uint128::zero();
+    Ok(PendingTransfer {
+        new_address: deps.api.addr_validate(new_address)?,
+        expires_at,
+    })
+}
+
+// only proposed address can accept the transfer before it expires
+fn check_pending_transfer(
+    env: &Env,
+    info: &MessageInfo,
+    pending_transfer: &PendingTransfer,
+) -> Result<(), ContractError> {
+    if info.sender != pending_transfer.new_address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(expires_at) = pending_transfer.expires_at {
+        if expires_at <= env.block.time.seconds() {
+            return Err(ContractError::TransferExpired {});
+        }
+    }
+
+    Ok(())
+}
+
+pub fn execute_update_end_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    end_time: u64,
+    reward_token: Option<AssetToken>,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    let current_time = env.block.time.seconds();
+
+    // permission check
+    if info.sender != campaign_info.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // new end time must be in the future
+    if end_time <= current_time.max(campaign_info.start_time) {
+        return Err(ContractError::InvalidTimeToUpdate {});
+    }
+
+    // campaign during max 3 years
+    if (end_time - campaign_info.start_time) > MAX_TIME_VALID {
+        return Err(ContractError::LimitStartDate {});
+    }
+
+    let mut res = Response::new();
+
+    // reward token deposited together with new end time
+    if let Some(reward_token) = reward_token {
+        ensure_not_paused(deps.as_ref())?;
+
+        let index = campaign_info
+            .reward_tokens
+            .iter()
+            .position(|token| token.info == reward_token.info)
+            .ok_or(ContractError::InvalidToken {})?;
+
+        let collect_res = collect_reward_token(&env, &info, &reward_token)?;
+        res = res
+            .add_submessages(collect_res.messages)
+            .add_attributes(collect_res.attributes)
+            .add_attribute("reward_token_amount", reward_token.amount.to_string());
+
+        let token = &mut campaign_info.reward_tokens[index];
+        token.amount = add_reward(token.amount, reward_token.amount)
+            .map_err(|_| ContractError::overflow("reward amount", None, None))?;
+        token.total_reward = add_reward(token.total_reward, reward_token.amount)
+            .map_err(|_| ContractError::overflow("total reward", None, None))?;
+    }
+
+    if campaign_info.start_time < current_time {
+        // checkpoint accrued reward at the change point, reward is not accrued after campaign
+        // is ended and nfts expired before current time are settled
+        checkpoint_pool(deps.storage, &campaign_info, current_time)?;
+
+        // reward restarts at current time, nfts which are ended by the end of campaign, not
+        // their lockup term, earn reward again
+        for term in campaign_info.lockup_term.iter() {
+            let mut index = TERM_REWARD_INDEXES.load(deps.storage, term.value)?;
+            index.last_update = current_time;
+            TERM_REWARD_INDEXES.save(deps.storage, term.value, &index)?;
+        }
+
+        // reward which is not accrued yet is distributed over the new remaining duration
+        let total_pending_reward =
+            calc_total_pending_reward(deps.storage, &campaign_info, current_time)?;
+        let remaining_duration = end_time - current_time;
+        for (reward_token, total_pending_reward) in campaign_info
+            .reward_tokens
+            .iter_mut()
+            .zip(total_pending_reward)
+        {
+            let remaining_reward = reward_token
+                .amount
+                .checked_sub(total_pending_reward)
+                .map_err(|_| ContractError::underflow("remaining reward", None, None))?;
+            reward_token.reward_per_second =
+                Decimal256::from_ratio(remaining_reward, remaining_duration);
+        }
+    } else {
+        let duration = end_time - campaign_info.start_time;
+        for reward_token in campaign_info.reward_tokens.iter_mut() {
+            reward_token.reward_per_second = Decimal256::from_ratio(reward_token.amount, duration);
+        }
+    }
+
+    campaign_info.end_time = end_time;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    Ok(res.add_attributes([
+        ("action", "update_end_time"),
+        ("owner", campaign_info.owner.as_ref()),
+        ("end_time", &end_time.to_string()),
+    ]))
+}
+
+pub fn execute_set_paused(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.paused = paused;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "set_paused"),
+        ("admin", config.admin.as_ref()),
+        ("paused", &paused.to_string()),
+    ]))
+}
+
+pub fn execute_set_emergency(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    emergency: bool,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.emergency = emergency;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes([
+        ("action", "set_emergency"),
+        ("admin", config.admin.as_ref()),
+        ("emergency", &emergency.to_string()),
+    ]))
+}
+
+pub fn execute_emergency_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // nfts can only be withdrawn regardless of lockup term in emergency mode
+    if !config.emergency {
+        return Err(ContractError::NotInEmergency {});
+    }
+
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    let mut staker = STAKERS_INFO
+        .may_load(deps.storage, info.sender.clone())?
+        .ok_or(ContractError::NoNftToUnStake {})?;
+    if staker.keys.is_empty() {
+        return Err(ContractError::NoNftToUnStake {});
+    }
 
     // max time calc pending reward is campaign_info.end_time
     let mut current_time = env.block.time.seconds();
@@ -523,108 +2091,152 @@ pub fn execute_claim_reward(
         current_time = campaign_info.end_time;
     }
 
-    // transfer pending reward in nft to staker
-    for key in staker_info.keys.iter() {
+    let mut res = Response::new();
+    for key in staker.keys.clone() {
         let nft_info = NFTS.load(deps.storage, (key.key, key.lockup_term))?;
 
-        // load TERM_REWARD_RATES
-        let term_reward_rates = TERM_REWARD_RATES.load(deps.storage, nft_info.lockup_term.value)?;
+        // pending reward of nft in staking period is forfeited and kept in pool
+        if let Some(nft_res) = remove_nft(
+            deps.storage,
+            &mut campaign_info,
+            &mut staker,
+            nft_info,
+            current_time,
+            UnstakeMode::Emergency,
+        )? {
+            res = res.add_submessages(nft_res.messages);
+        }
+    }
 
-        let total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, nft_info.lockup_term.value)?;
+    STAKERS_INFO.save(deps.storage, info.sender.clone(), &staker)?;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
 
-        let expiration_times =
-            TERM_EXPIRATION_TIMES.load(deps.storage, nft_info.lockup_term.value)?;
+    Ok(res.add_attributes([
+        ("action", "emergency_withdraw"),
+        ("owner", info.sender.as_ref()),
+    ]))
+}
 
-        let (mut new_nft_info, new_term_reward_rates, new_total_staking, new_expiration_times) =
-            calculate_reward(
-                nft_info,
-                term_reward_rates.clone(),
-                expiration_times,
-                total_staking,
-                current_time,
-                campaign_info.end_time,
-                campaign_info.reward_per_second,
-            );
+// staking, claiming and adding reward are blocked while campaign is paused
+fn ensure_not_paused(deps: Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
 
-        pending_reward_staker =
-            add_reward(pending_reward_staker, new_nft_info.pending_reward).unwrap();
+    Ok(())
+}
 
-        //update pending reward for nft = 0 because pending reward in nft are transferred to staker
-        new_nft_info.pending_reward = Uint128::zero();
-        NFTS.save(deps.storage, (key.key, key.lockup_term), &new_nft_info)?;
+pub fn execute_update_campaign(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    update_campaign: UpdateCampaignInfo,
+) -> Result<Response, ContractError> {
+    // load campaign info
+    let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+
+    let current_time = env.block.time.seconds();
+
+    // only owner can update campaign
+    if campaign_info.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut res = Response::new();
+
+    // display metadata can be updated at any time
+    if let Some(campaign_name) = update_campaign.campaign_name {
+        campaign_info.campaign_name = campaign_name;
+        res = res.add_attribute("campaign_name", &campaign_info.campaign_name);
+    }
+    if let Some(campaign_image) = update_campaign.campaign_image {
+        campaign_info.campaign_image = campaign_image;
+        res = res.add_attribute("campaign_image", &campaign_info.campaign_image);
+    }
+    if let Some(campaign_description) = update_campaign.campaign_description {
+        campaign_info.campaign_description = campaign_description;
+        res = res.add_attribute("campaign_description", &campaign_info.campaign_description);
+    }
+
+    validate_campaign_metadata(
+        &campaign_info.campaign_name,
+        &campaign_info.campaign_image,
+        &campaign_info.campaign_description,
+    )?;
 
-        // update term reward rates
-        TERM_REWARD_RATES.save(
-            deps.storage,
-            new_nft_info.lockup_term.value,
-            &new_term_reward_rates,
-        )?;
-        TOTAL_STAKING_BY_TERM.save(
-            deps.storage,
-            new_nft_info.lockup_term.value,
-            &new_total_staking,
-        )?;
-        TERM_EXPIRATION_TIMES.save(
-            deps.storage,
-            new_nft_info.lockup_term.value,
-            &new_expiration_times,
-        )?;
+    // schedule of campaign can only be updated before campaign starts
+    if (update_campaign.limit_per_staker.is_some()
+        || update_campaign.lockup_term.is_some()
+        || update_campaign.start_time.is_some())
+        && campaign_info.start_time <= current_time
+    {
+        return Err(ContractError::InvalidTimeToUpdate {});
     }
 
-    // amount reward claim must be less than or equal reward in staker
-    if amount > staker_info.reward_debt + pending_reward_staker {
-        return Err(ContractError::InsufficientBalance {});
+    if let Some(limit_per_staker) = update_campaign.limit_per_staker {
+        campaign_info.limit_per_staker = limit_per_staker;
+        res = res.add_attribute("limit_per_staker", limit_per_staker.to_string());
     }
 
-    let mut res = Response::new();
+    // nobody can stake before campaign starts, so terms have no staking state to keep
+    let reset_terms = update_campaign.lockup_term.is_some() || update_campaign.start_time.is_some();
 
-    match campaign_info.reward_token.info.clone() {
-        TokenInfo::Token { contract_addr } => {
-            // execute cw20 transfer msg from info.sender to contract
-            res = res.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: contract_addr.to_string(),
-                msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient: info.sender.to_string(),
-                    amount,
-                })?,
-                funds: vec![],
-            }));
+    if let Some(lockup_term) = update_campaign.lockup_term {
+        validate_lockup_terms(deps.as_ref(), &lockup_term)?;
 
-            res = res.add_attributes([
-                ("reward_token_info", contract_addr),
-                ("reward_claim_amount", amount.to_string()),
-            ]);
+        for term in campaign_info.lockup_term.iter() {
+            TERM_REWARD_INDEXES.remove(deps.storage, term.value);
+            NUMBER_OF_NFTS.remove(deps.storage, term.value);
+        }
+        for term in lockup_term.iter() {
+            NUMBER_OF_NFTS.save(deps.storage, term.value, &0u64)?;
+        }
+
+        res = res.add_attribute("lockup_term", format!("{:?}", &lockup_term));
+        campaign_info.lockup_term = lockup_term;
+    }
+
+    if let Some(start_time) = update_campaign.start_time {
+        // start time must be in the future and less than end time
+        if start_time <= current_time || start_time >= campaign_info.end_time {
+            return Err(ContractError::InvalidTimeToUpdate {});
+        }
 
-            // update staker info
-            staker_info.reward_claimed = add_reward(staker_info.reward_claimed, amount).unwrap();
-            staker_info.reward_debt = staker_info
-                .reward_debt
-                .saturating_add(pending_reward_staker)
-                .saturating_sub(amount);
+        // campaign during max 3 years
+        if (campaign_info.end_time - start_time) > MAX_TIME_VALID {
+            return Err(ContractError::LimitStartDate {});
+        }
 
-            STAKERS_INFO.save(deps.storage, info.sender, &staker_info)?;
+        // reward is distributed over new duration of campaign
+        campaign_info.start_time = start_time;
+        let duration = campaign_info.end_time - start_time;
+        for reward_token in campaign_info.reward_tokens.iter_mut() {
+            reward_token.reward_per_second = Decimal256::from_ratio(reward_token.amount, duration);
+        }
 
-            // update reward total and reward claimed for campaign
-            campaign_info.reward_token.amount =
-                sub_reward(campaign_info.reward_token.amount, amount).unwrap();
-            campaign_info.total_reward_claimed =
-                add_reward(campaign_info.total_reward_claimed, amount).unwrap();
+        res = res.add_attribute("start_time", start_time.to_string());
+    }
 
-            // save campaign info
-            CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+    // reward indexes of lockup terms start from start time of campaign
+    if reset_terms {
+        for term in campaign_info.lockup_term.iter() {
+            init_term_reward_index(deps.storage, &campaign_info, term.value)?;
         }
-        TokenInfo::NativeToken { denom: _ } => {}
     }
+
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
     Ok(res.add_attributes([
-        ("action", "claim_reward"),
+        ("action", "update_campaign"),
         ("owner", campaign_info.owner.as_ref()),
     ]))
 }
 
-pub fn execute_withdraw_reward(
+pub fn execute_update_trait_multipliers(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
+    trait_multipliers: Vec<TraitMultiplier>,
 ) -> Result<Response, ContractError> {
     // load campaign info
     let mut campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
@@ -634,216 +2246,387 @@ pub fn execute_withdraw_reward(
         return Err(ContractError::Unauthorized {});
     }
 
-    // campaing must be ended then withdraw remaining reward
-    if campaign_info.end_time > env.block.time.seconds() {
-        return Err(ContractError::InvalidTimeToWithdrawReward {});
+    validate_trait_multipliers(&trait_multipliers)?;
+
+    let trait_multipliers_str = trait_multipliers
+        .iter()
+        .map(|trait_multiplier| trait_multiplier.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    // nfts staked before keep their weight
+    campaign_info.trait_multipliers = trait_multipliers;
+    CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+
+    let mut res = Response::new().add_attributes([
+        ("action", "update_trait_multipliers"),
+        ("owner", campaign_info.owner.as_ref()),
+    ]);
+
+    // attribute value must not be empty
+    if !trait_multipliers_str.is_empty() {
+        res = res.add_attribute("trait_multipliers", trait_multipliers_str);
     }
 
-    // total_pending_reward = previous total reward + total in rates - reward claimed
-    let mut total_pending_reward = PREVIOUS_TOTAL_REWARD.load(deps.storage)?;
+    Ok(res)
+}
+
+// validate limit character campaign name, campaign image & campaign description
+fn validate_campaign_metadata(
+    campaign_name: &str,
+    campaign_image: &str,
+    campaign_description: &str,
+) -> Result<(), ContractError> {
+    if campaign_name.len() > MAX_LENGTH_NAME {
+        return Err(ContractError::LimitCharacter {
+            max: MAX_LENGTH_NAME.to_string(),
+        });
+    }
 
-    // time to calc pending reward
-    let current_time = campaign_info.end_time;
+    if campaign_image.len() > MAX_LENGTH_IMAGE {
+        return Err(ContractError::LimitCharacter {
+            max: MAX_LENGTH_IMAGE.to_string(),
+        });
+    }
 
-    // load TERM_REWARD_RATES
-    for term in campaign_info.lockup_term.iter() {
-        let mut term_reward_rates = TERM_REWARD_RATES.load(deps.storage, term.value)?;
-        let expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, term.value)?;
-        let mut total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, term.value)?;
-        for &end_time in &expiration_times {
-            if end_time <= current_time {
-                let (updated_reward_rate, t) =
-                    update_reward_rate(term_reward_rates, total_staking, end_time, -1);
-                term_reward_rates = updated_reward_rate;
-                total_staking = t;
-            }
-        }
+    if campaign_description.len() > MAX_LENGTH_DESCRIPTION {
+        return Err(ContractError::LimitCharacter {
+            max: MAX_LENGTH_DESCRIPTION.to_string(),
+        });
+    }
 
-        let (final_reward_rate, _) =
-            update_reward_rate(term_reward_rates, total_staking, current_time, 0);
+    Ok(())
+}
 
-        if final_reward_rate.len() > 1 {
-            for i in 0..(final_reward_rate.len() - 1) {
-                let current = &final_reward_rate[i];
-                let next = &final_reward_rate[i + 1];
+// percents of lockup terms must sum to 100, penalty percent must be less than or equal 100
+// and penalty recipient must be valid address
+fn validate_lockup_terms(deps: Deps, lockup_term: &[LockupTerm]) -> Result<(), ContractError> {
+    let total_percent = lockup_term
+        .iter()
+        .try_fold(Uint128::zero(), |acc, term| acc.checked_add(term.percent))
+        .map_err(|_| ContractError::overflow("total percent", None, None))?;
 
-                if current.rate != 0 && next.timestamp > current.timestamp {
-                    let duration = (next.timestamp - current.timestamp) as u128;
-                    let reward_per_second = campaign_info.reward_per_second.u128();
-                    let term_percent = term.percent.u128();
+    if total_percent != Uint128::from(100u128) {
+        return Err(ContractError::InvalidFunds {});
+    }
 
-                    let product = duration
-                        .saturating_mul(reward_per_second)
-                        .saturating_mul(term_percent)
-                        .saturating_div(100u128);
+    for term in lockup_term.iter() {
+        // lockup term must be longer than 0 second
+        if term.value == 0 {
+            return Err(ContractError::InvalidLockupTerm {});
+        }
 
-                    total_pending_reward =
-                        Uint128::from(total_pending_reward.u128().saturating_add(product));
-                }
+        if let Some(penalty) = &term.early_unstake_penalty {
+            if penalty.percent > Uint128::from(100u128) {
+                return Err(ContractError::InvalidEarlyUnstakePenalty {});
+            }
+            if let Some(recipient) = &penalty.recipient {
+                deps.api.addr_validate(recipient)?;
             }
         }
     }
 
-    total_pending_reward = total_pending_reward.saturating_sub(campaign_info.total_reward_claimed);
+    Ok(())
+}
 
-    // reward remaining = current total reward - total pending reward
-    let withdraw_reward = campaign_info
-        .reward_token
-        .amount
-        .checked_sub(total_pending_reward)
-        .unwrap();
+// multipliers must be greater than 0 and not duplicated by trait_type and value
+fn validate_trait_multipliers(trait_multipliers: &[TraitMultiplier]) -> Result<(), ContractError> {
+    if trait_multipliers.len() > MAX_TRAIT_MULTIPLIERS {
+        return Err(ContractError::InvalidTraitMultiplier {});
+    }
+
+    for (i, trait_multiplier) in trait_multipliers.iter().enumerate() {
+        if trait_multiplier.multiplier.is_zero() {
+            return Err(ContractError::InvalidTraitMultiplier {});
+        }
+
+        if trait_multipliers[..i].iter().any(|t| {
+            t.trait_type == trait_multiplier.trait_type && t.value == trait_multiplier.value
+        }) {
+            return Err(ContractError::AlreadyExist {});
+        }
+    }
+
+    Ok(())
+}
+
+// weight of nft = collection weight * WEIGHT_PRECISION * multipliers of its traits,
+// nft without token_id has no traits
+fn nft_weight(
+    deps: Deps,
+    campaign_info: &CampaignInfo,
+    collection: &AllowedCollection,
+    token_id: Option<&str>,
+) -> Result<u64, ContractError> {
+    let overflow = || ContractError::overflow("nft weight", None, None);
+
+    let weight = collection
+        .weight
+        .checked_mul(WEIGHT_PRECISION)
+        .ok_or_else(overflow)?;
+
+    // no need to query nft metadata if campaign has no trait multipliers
+    let token_id = match token_id {
+        Some(token_id) if !campaign_info.trait_multipliers.is_empty() => token_id,
+        _ => return Ok(weight),
+    };
+
+    // collection without on-chain metadata has no traits
+    let nft_info: StdResult<cw721::NftInfoResponse<Option<Metadata>>> =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: collection.collection_addr.clone(),
+            msg: to_binary(&Cw721QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            })?,
+        }));
+    let attributes = match nft_info {
+        Ok(cw721::NftInfoResponse {
+            extension:
+                Some(Metadata {
+                    attributes: Some(attributes),
+                    ..
+                }),
+            ..
+        }) => attributes,
+        _ => return Ok(weight),
+    };
 
+    let weight = campaign_info
+        .trait_multipliers
+        .iter()
+        .filter(|trait_multiplier| {
+            attributes.iter().any(|attribute| {
+                attribute.trait_type == trait_multiplier.trait_type
+                    && attribute.value == trait_multiplier.value
+            })
+        })
+        .try_fold(Uint128::from(weight), |weight, trait_multiplier| {
+            weight
+                .checked_multiply_ratio(
+                    trait_multiplier.multiplier.atomics(),
+                    Decimal::one().atomics(),
+                )
+                .map_err(|_| overflow())
+        })?;
+
+    // weight of nft must be at least 1 to be counted in reward rates
+    Ok(u64::try_from(weight.u128()).map_err(|_| overflow())?.max(1))
+}
+
+// prepare response to collect reward token from sender
+// cw20 token is transferred by allowance, native token must be sent in funds
+fn collect_reward_token(
+    env: &Env,
+    info: &MessageInfo,
+    reward_token: &AssetToken,
+) -> Result<Response, ContractError> {
     let mut res = Response::new();
-    match campaign_info.reward_token.info.clone() {
+
+    // we need determine the reward token is native token or cw20 token
+    match &reward_token.info {
         TokenInfo::Token { contract_addr } => {
-            // execute cw20 transfer msg from info.sender to contract
+            // native funds would be locked in contract
+            nonpayable(info).map_err(|_| ContractError::InvalidFunds {})?;
 
+            // execute cw20 transfer msg from info.sender to contract
             res = res.add_message(CosmosMsg::Wasm(WasmMsg::Execute {
                 contract_addr: contract_addr.to_string(),
-                msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                    recipient: info.sender.to_string(),
-                    amount: withdraw_reward,
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount: reward_token.amount,
                 })?,
                 funds: vec![],
             }));
 
-            res = res.add_attributes([
-                ("reward_token_info", contract_addr),
-                ("withdraw_reward_amount", withdraw_reward.to_string()),
-            ]);
+            // add token info to response
+            res = res.add_attribute("reward_token_info", contract_addr);
+        }
+        TokenInfo::NativeToken { denom } => {
+            // the funds sent must be exactly the amount of reward denom
+            let received = must_pay(info, denom).map_err(|_| ContractError::InvalidFunds {})?;
+            if received != reward_token.amount {
+                return Err(ContractError::InvalidFunds {});
+            }
 
-            // update reward total and reward claimed for campaign
-            campaign_info.reward_token.amount =
-                sub_reward(campaign_info.reward_token.amount, withdraw_reward).unwrap();
-            CAMPAIGN_INFO.save(deps.storage, &campaign_info)?;
+            // add token info to response
+            res = res.add_attribute("reward_token_info", denom);
         }
-        TokenInfo::NativeToken { denom: _ } => {}
     }
-    Ok(res.add_attributes([
-        ("action", "withdraw_reward"),
-        ("owner", campaign_info.owner.as_ref()),
-    ]))
-}
 
-pub fn execute_reset_pool(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-) -> Result<Response, ContractError> {
-    let config: Config = CONFIG.load(deps.storage)?;
-    // load campaign info
-    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+    Ok(res)
+}
 
-    // permission check
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+// increase reward amount of a reward token and recalculate its reward_per_second
+fn deposit_reward(
+    storage: &mut dyn Storage,
+    campaign_info: &mut CampaignInfo,
+    index: usize,
+    amount: Uint128,
+    current_time: u64,
+) -> Result<(), ContractError> {
+    // reward can not be added after campaign is ended
+    if campaign_info.end_time <= current_time {
+        return Err(ContractError::InvalidTimeToAddReward {});
     }
 
-    // max time calc pending reward is campaign_info.end_time
-    let mut current_time = env.block.time.seconds();
-    if campaign_info.end_time < env.block.time.seconds() {
-        current_time = campaign_info.end_time;
+    if campaign_info.start_time < current_time {
+        // reward added after campaign starts is distributed over remaining duration,
+        // reward accrued until now keeps current reward_per_second
+        checkpoint_pool(storage, campaign_info, current_time)?;
+
+        let remaining_duration = campaign_info.end_time - current_time;
+        let reward_token = &mut campaign_info.reward_tokens[index];
+
+        reward_token.amount = reward_token
+            .amount
+            .checked_add(amount)
+            .map_err(|_| ContractError::overflow("reward amount", None, None))?;
+        reward_token.reward_per_second = reward_token
+            .reward_per_second
+            .checked_add(Decimal256::from_ratio(amount, remaining_duration))
+            .map_err(|_| ContractError::overflow("reward per second", None, None))?;
+        reward_token.total_reward = reward_token
+            .total_reward
+            .checked_add(amount)
+            .map_err(|_| ContractError::overflow("total reward", None, None))?;
+    } else {
+        // reward added before campaign starts is distributed over the whole campaign
+        let duration = campaign_info.end_time - campaign_info.start_time;
+        let reward_token = &mut campaign_info.reward_tokens[index];
+
+        reward_token.amount = reward_token
+            .amount
+            .checked_add(amount)
+            .map_err(|_| ContractError::overflow("reward amount", None, None))?;
+        reward_token.reward_per_second = Decimal256::from_ratio(reward_token.amount, duration);
+        reward_token.total_reward = reward_token
+            .total_reward
+            .checked_add(amount)
+            .map_err(|_| ContractError::overflow("total reward", None, None))?;
     }
 
-    let mut current_total_reward = PREVIOUS_TOTAL_REWARD.load(deps.storage)?;
-
-    // load TERM_REWARD_RATES
-    for term in campaign_info.lockup_term.iter() {
-        let mut term_reward_rates = TERM_REWARD_RATES.load(deps.storage, term.value)?;
-        let mut expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, term.value)?;
-        let mut total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, term.value)?;
-
-        let nft_count_by_term = NUMBER_OF_NFTS.load(deps.storage, term.value)?;
-
-        let nfts_by_term = (0..nft_count_by_term)
-            .map(|key| NFTS.load(deps.storage, (key + 1, term.value)))
-            .filter_map(Result::ok)
-            .collect::<Vec<_>>();
-
-        for nft_info in nfts_by_term.iter() {
-            let (new_nft_info, new_term_reward_rates, new_total_staking, new_expiration_times) =
-                calculate_reward(
-                    nft_info.clone(),
-                    term_reward_rates.clone(),
-                    expiration_times.clone(),
-                    total_staking,
-                    current_time,
-                    campaign_info.end_time,
-                    campaign_info.reward_per_second,
-                );
-            term_reward_rates = new_term_reward_rates;
-            total_staking = new_total_staking;
-            expiration_times = new_expiration_times;
-            NFTS.save(deps.storage, (nft_info.key, term.value), &new_nft_info)?;
-        }
+    Ok(())
+}
 
-        // calculate total pending reward in current reward_rates
-        if term_reward_rates.len() > 1 {
-            for i in 0..(term_reward_rates.len() - 1) {
-                let current = &term_reward_rates[i];
-                let next = &term_reward_rates[i + 1];
+// find allowed collection of campaign by collection address
+fn allowed_collection(
+    campaign_info: &CampaignInfo,
+    collection_addr: &str,
+) -> Result<AllowedCollection, ContractError> {
+    campaign_info
+        .allowed_collections
+        .iter()
+        .find(|collection| collection.collection_addr == collection_addr)
+        .cloned()
+        .ok_or(ContractError::InvalidCollection {})
+}
 
-                if current.rate != 0 && next.timestamp > current.timestamp {
-                    let duration = (next.timestamp - current.timestamp) as u128;
-                    let reward_per_second = campaign_info.reward_per_second.u128();
-                    let term_percent = term.percent.u128();
+// reward per second of each reward token, in the order of campaign reward tokens
+fn reward_per_second(campaign_info: &CampaignInfo) -> Vec<Decimal256> {
+    campaign_info
+        .reward_tokens
+        .iter()
+        .map(|reward_token| reward_token.reward_per_second)
+        .collect()
+}
 
-                    let product = duration
-                        .saturating_mul(reward_per_second)
-                        .saturating_mul(term_percent)
-                        .saturating_div(100u128);
+// total pending reward of each reward token at current_time
+// = total reward accrued by lockup terms - dust - total reward claimed - total penalty
+fn calc_total_pending_reward(
+    storage: &dyn Storage,
+    campaign_info: &CampaignInfo,
+    current_time: u64,
+) -> Result<Vec<Uint128>, ContractError> {
+    let mut total_reward = vec![Decimal256::zero(); campaign_info.reward_tokens.len()];
 
-                    current_total_reward =
-                        Uint128::from(current_total_reward.u128().saturating_add(product));
-                }
-            }
+    for term in campaign_info.lockup_term.iter() {
+        let (index, _) = accrue_term(storage, campaign_info, term, current_time)?;
+
+        // dust of nfts which left lockup term is never paid, so it is not reserved
+        for ((total_reward, term_total_reward), term_total_dust) in
+            total_reward.iter_mut().zip(index.total_reward).zip(
+                index
+                    .total_dust
+                    .into_iter()
+                    .chain(iter::repeat(Decimal256::zero())),
+            )
+        {
+            *total_reward = term_total_reward
+                .checked_sub(term_total_dust)
+                .map_err(|_| ContractError::underflow("total dust", Some(term.value), None))?
+                .checked_add(*total_reward)
+                .map_err(|_| ContractError::overflow("total reward", Some(term.value), None))?;
         }
-
-        // update reward rates for future
-        let updated_term_reward_rates = term_reward_rates
-            .last()
-            .map_or(Vec::new(), |last_element| vec![last_element.clone()]);
-
-        TERM_REWARD_RATES.save(deps.storage, term.value, &updated_term_reward_rates)?;
-        TOTAL_STAKING_BY_TERM.save(deps.storage, term.value, &total_staking)?;
-        TERM_EXPIRATION_TIMES.save(deps.storage, term.value, &expiration_times)?;
     }
 
-    PREVIOUS_TOTAL_REWARD.save(deps.storage, &current_total_reward)?;
-
-    Ok(
-        Response::new()
-            .add_attributes([("action", "reset_pool"), ("admin", config.admin.as_ref())]),
-    )
+    // reward of each nft is rounded down, so it never exceeds accrued reward rounded down
+    total_reward
+        .into_iter()
+        .zip(campaign_info.reward_tokens.iter())
+        .map(|(total_reward, reward_token)| {
+            Uint128::try_from(total_reward.to_uint_floor())
+                .map_err(|_| ContractError::overflow("total pending reward", None, None))?
+                .checked_sub(reward_token.total_reward_claimed)
+                .and_then(|res| res.checked_sub(reward_token.total_penalty))
+                .map_err(|_| ContractError::underflow("total pending reward", None, None))
+        })
+        .collect()
 }
 
-pub fn execute_update_admin(
-    deps: DepsMut,
-    _env: Env,
-    info: MessageInfo,
-    admin: String,
-) -> Result<Response, ContractError> {
-    let config: Config = CONFIG.load(deps.storage)?;
-
-    // permission check
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+// prepare message to transfer reward token from contract to recipient
+fn transfer_reward_msg(
+    token_info: &TokenInfo,
+    recipient: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    match token_info {
+        TokenInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        })),
+        TokenInfo::NativeToken { denom } => Ok(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: denom.to_string(),
+                amount,
+            }],
+        })),
     }
-
-    let new_config = Config {
-        admin: deps.api.addr_validate(&admin).unwrap(),
-    };
-
-    // save config admin
-    CONFIG.save(deps.storage, &new_config)?;
-
-    Ok(Response::new().add_attributes([("action", "update_admin"), ("admin", &admin)]))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
         QueryMsg::CampaignInfo {} => Ok(to_binary(&query_campaign_info(deps)?)?),
+        QueryMsg::Status {} => Ok(to_binary(&query_status(deps)?)?),
+        QueryMsg::PendingOwner {} => Ok(to_binary(&PENDING_OWNER.may_load(deps.storage)?)?),
+        QueryMsg::PendingAdmin {} => Ok(to_binary(&PENDING_ADMIN.may_load(deps.storage)?)?),
+        QueryMsg::SimulateStake {
+            collection_addr,
+            token_id,
+            lockup_term,
+            count,
+            at_time,
+        } => Ok(to_binary(&query_simulate_stake(
+            deps,
+            env,
+            collection_addr,
+            token_id,
+            lockup_term,
+            count,
+            at_time,
+        )?)?),
+        QueryMsg::StakerPendingReward { owner } => {
+            Ok(to_binary(&query_staker_pending_reward(deps, env, owner)?)?)
+        }
+        QueryMsg::RewardRecipient { owner } => Ok(to_binary(
+            &REWARD_RECIPIENTS.may_load(deps.storage, owner)?,
+        )?),
         QueryMsg::NftInfo { nft_key } => Ok(to_binary(&query_nft_info(deps, env, nft_key)?)?),
         QueryMsg::Nfts {
             lockup_term,
@@ -858,9 +2641,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         )?)?),
         QueryMsg::NftStaked { owner } => Ok(to_binary(&query_staker_info(deps, env, owner)?)?),
         QueryMsg::TotalPendingReward {} => Ok(to_binary(&query_total_pending_reward(deps, env)?)?),
-        QueryMsg::TermRewardRates { term_value } => {
-            Ok(to_binary(&query_term_reward_rates(deps, term_value)?)?)
-        }
+        QueryMsg::TermRewardIndex { term_value } => Ok(to_binary(
+            &TERM_REWARD_INDEXES.load(deps.storage, term_value)?,
+        )?),
+        QueryMsg::ResetPoolProgress {} => Ok(to_binary(&query_reset_pool_progress(deps, env)?)?),
     }
 }
 
@@ -870,6 +2654,15 @@ fn query_campaign_info(deps: Deps) -> Result<CampaignInfo, ContractError> {
     Ok(campaign_info)
 }
 
+fn query_status(deps: Deps) -> Result<StatusResponse, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    Ok(StatusResponse {
+        paused: config.paused,
+        emergency: config.emergency,
+    })
+}
+
 fn query_nft_info(deps: Deps, env: Env, nft_key: NftKey) -> Result<NftInfo, ContractError> {
     let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
     let nft_info: NftInfo = NFTS.load(deps.storage, (nft_key.key, nft_key.lockup_term))?;
@@ -879,23 +2672,21 @@ fn query_nft_info(deps: Deps, env: Env, nft_key: NftKey) -> Result<NftInfo, Cont
         current_time = campaign_info.end_time;
     }
 
-    let term_reward_rates = TERM_REWARD_RATES.load(deps.storage, nft_info.lockup_term.value)?;
-
-    let total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, nft_info.lockup_term.value)?;
-
-    let expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, nft_info.lockup_term.value)?;
+    let (index, expired) = accrue_term(
+        deps.storage,
+        &campaign_info,
+        &nft_info.lockup_term,
+        current_time,
+    )?;
 
-    let (new_nft_info, _, _, _) = calculate_reward(
+    query_nft_reward(
+        deps.storage,
+        &campaign_info,
+        &index,
+        &expired,
         nft_info,
-        term_reward_rates,
-        expiration_times,
-        total_staking,
         current_time,
-        campaign_info.end_time,
-        campaign_info.reward_per_second,
-    );
-
-    Ok(new_nft_info)
+    )
 }
 
 fn query_nfts(
@@ -922,23 +2713,24 @@ fn query_nfts(
         .take(limit)
         .collect::<Vec<_>>();
 
-    let term_reward_rates = TERM_REWARD_RATES.load(deps.storage, lockup_term)?;
-
-    let total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, lockup_term)?;
-
-    let expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, lockup_term)?;
-
-    for nft_info in nfts.iter_mut() {
-        let (new_nft_info, _, _, _) = calculate_reward(
-            nft_info.clone(),
-            term_reward_rates.clone(),
-            expiration_times.clone(),
-            total_staking,
+    if let Some(nft_info) = nfts.first() {
+        let (index, expired) = accrue_term(
+            deps.storage,
+            &campaign_info,
+            &nft_info.lockup_term,
             current_time,
-            campaign_info.end_time,
-            campaign_info.reward_per_second,
-        );
-        *nft_info = new_nft_info;
+        )?;
+
+        for nft_info in nfts.iter_mut() {
+            *nft_info = query_nft_reward(
+                deps.storage,
+                &campaign_info,
+                &index,
+                &expired,
+                nft_info.clone(),
+                current_time,
+            )?;
+        }
     }
 
     Ok(nfts)
@@ -949,23 +2741,26 @@ fn query_staker_info(
     _env: Env,
     owner: Addr,
 ) -> Result<StakerRewardAssetInfo, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
     let staker_asset: StakerRewardAssetInfo =
         STAKERS_INFO
             .load(deps.storage, owner)
             .unwrap_or(StakerRewardAssetInfo {
                 keys: vec![],
-                reward_debt: Uint128::zero(),
-                reward_claimed: Uint128::zero(),
+                reward_debt: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
+                reward_claimed: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
             });
 
     Ok(staker_asset)
 }
 
-fn query_total_pending_reward(deps: Deps, env: Env) -> Result<Uint128, ContractError> {
+fn query_staker_pending_reward(
+    deps: Deps,
+    env: Env,
+    owner: Addr,
+) -> Result<StakerPendingRewardResponse, ContractError> {
     let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
-
-    // total pending reward = previous total reward + total reward in rates - total reward claimed
-    let mut total_pending_reward: Uint128 = PREVIOUS_TOTAL_REWARD.load(deps.storage)?;
+    let staker_asset = query_staker_info(deps, env.clone(), owner)?;
 
     // max time to calc = campaign_info.end_time
     let mut current_time = env.block.time.seconds();
@@ -973,52 +2768,228 @@ fn query_total_pending_reward(deps: Deps, env: Env) -> Result<Uint128, ContractE
         current_time = campaign_info.end_time;
     }
 
-    // load TERM_REWARD_RATES
-    for term in campaign_info.lockup_term.iter() {
-        let mut term_reward_rates = TERM_REWARD_RATES.load(deps.storage, term.value)?;
-        let expiration_times = TERM_EXPIRATION_TIMES.load(deps.storage, term.value)?;
-        let mut total_staking = TOTAL_STAKING_BY_TERM.load(deps.storage, term.value)?;
-        for &end_time in &expiration_times {
-            if end_time <= current_time {
-                let (updated_reward_rate, t) =
-                    update_reward_rate(term_reward_rates, total_staking, end_time, -1);
-                term_reward_rates = updated_reward_rate;
-                total_staking = t;
+    // reward settled by unstake or claim is claimable
+    let mut total_pending_reward = staker_asset.reward_debt;
+    let mut nfts = vec![];
+
+    for key in staker_asset.keys {
+        let nft_info: NftInfo = NFTS.load(deps.storage, (key.key, key.lockup_term))?;
+
+        let (index, expired) = accrue_term(
+            deps.storage,
+            &campaign_info,
+            &nft_info.lockup_term,
+            current_time,
+        )?;
+        let new_nft_info = query_nft_reward(
+            deps.storage,
+            &campaign_info,
+            &index,
+            &expired,
+            nft_info,
+            current_time,
+        )?;
+
+        // reward of nft in staking period with penalty is not claimable, same as claim reward
+        let unlocked =
+            new_nft_info.is_end_reward || new_nft_info.lockup_term.early_unstake_penalty.is_none();
+        if unlocked {
+            for (total, pending_reward) in total_pending_reward
+                .iter_mut()
+                .zip(new_nft_info.pending_reward.iter())
+            {
+                *total = add_reward(*total, *pending_reward).map_err(|_| {
+                    ContractError::overflow(
+                        "staker pending reward",
+                        Some(key.lockup_term),
+                        Some(key.key),
+                    )
+                })?;
             }
         }
 
-        let (final_reward_rate, _) =
-            update_reward_rate(term_reward_rates, total_staking, current_time, 0);
+        nfts.push(NftPendingReward {
+            key,
+            token_id: new_nft_info.token_id,
+            collection_addr: new_nft_info.collection_addr,
+            pending_reward: to_asset_tokens(&campaign_info, new_nft_info.pending_reward),
+            unlocked,
+        });
+    }
+
+    Ok(StakerPendingRewardResponse {
+        total_pending_reward: to_asset_tokens(&campaign_info, total_pending_reward),
+        nfts,
+    })
+}
 
-        if final_reward_rate.len() > 1 {
-            for i in 0..(final_reward_rate.len() - 1) {
-                let current = &final_reward_rate[i];
-                let next = &final_reward_rate[i + 1];
+// pair amounts with reward tokens of campaign, same order as reward_tokens
+fn to_asset_tokens(campaign_info: &CampaignInfo, amounts: Vec<Uint128>) -> Vec<AssetToken> {
+    campaign_info
+        .reward_tokens
+        .iter()
+        .zip(amounts)
+        .map(|(reward_token, amount)| AssetToken {
+            info: reward_token.info.clone(),
+            amount,
+        })
+        .collect()
+}
 
-                if current.rate != 0 && next.timestamp > current.timestamp {
-                    let duration = (next.timestamp - current.timestamp) as u128;
-                    let reward_per_second = campaign_info.reward_per_second.u128();
-                    let term_percent = term.percent.u128();
+fn query_simulate_stake(
+    deps: Deps,
+    env: Env,
+    collection_addr: String,
+    token_id: Option<String>,
+    lockup_term: u64,
+    count: u64,
+    at_time: Option<u64>,
+) -> Result<SimulateStakeResponse, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
 
-                    let product = duration
-                        .saturating_mul(reward_per_second)
-                        .saturating_mul(term_percent)
-                        .saturating_div(100u128);
+    let lockup_term = campaign_info
+        .lockup_term
+        .iter()
+        .find(|term| term.value == lockup_term)
+        .ok_or(ContractError::InvalidLockupTerm {})?
+        .clone();
 
-                    total_pending_reward =
-                        Uint128::from(total_pending_reward.u128().saturating_add(product));
-                }
-            }
-        }
+    if count == 0 {
+        return Err(ContractError::Std(StdError::generic_err(
+            "## Count must be greater than 0 ##",
+        )));
     }
 
-    total_pending_reward = total_pending_reward.saturating_sub(campaign_info.total_reward_claimed);
+    // nft can only be staked from now while campaign is active
+    let start_time = at_time.unwrap_or_else(|| env.block.time.seconds());
+    if start_time < env.block.time.seconds()
+        || campaign_info.start_time >= start_time
+        || campaign_info.end_time <= start_time
+    {
+        return Err(ContractError::InvalidTimeToStakeNft {});
+    }
+
+    // simulated nft has weight of its collection and traits of token_id if it is given
+    let collection = allowed_collection(&campaign_info, &collection_addr)?;
+    let weight = nft_weight(deps, &campaign_info, &collection, token_id.as_deref())?;
+
+    let nft_info = NftInfo {
+        key: 0,
+        token_id: token_id.unwrap_or_default(),
+        collection_addr: Addr::unchecked(collection.collection_addr),
+        weight,
+        owner: Addr::unchecked(""),
+        pending_reward: vec![Uint128::zero(); campaign_info.reward_tokens.len()],
+        lockup_term: lockup_term.clone(),
+        is_end_reward: false,
+        start_time,
+        time_calc: start_time,
+        end_time: start_time.checked_add(lockup_term.value).ok_or_else(|| {
+            ContractError::overflow("nft end time", Some(lockup_term.value), None)
+        })?,
+    };
+    let end_time = nft_info.end_time.min(campaign_info.end_time);
+
+    let expirations = load_expirations(deps.storage, lockup_term.value, None, None)?;
+
+    let reward_per_nft = simulate_stake(
+        nft_info,
+        count,
+        TERM_REWARD_INDEXES.load(deps.storage, lockup_term.value)?,
+        &expirations,
+        campaign_info.end_time,
+        &reward_per_second(&campaign_info),
+    )?;
+
+    let total_reward = reward_per_nft
+        .iter()
+        .map(|reward| {
+            reward.checked_mul(Uint128::from(count)).map_err(|_| {
+                ContractError::overflow("simulate total reward", Some(lockup_term.value), None)
+            })
+        })
+        .collect::<Result<Vec<Uint128>, ContractError>>()?;
+
+    // reward per nft scaled to one year
+    let annual_reward_per_nft = reward_per_nft
+        .iter()
+        .map(|reward| {
+            reward
+                .checked_multiply_ratio(SECONDS_PER_YEAR, end_time - start_time)
+                .map_err(|_| {
+                    ContractError::overflow("simulate annual reward", Some(lockup_term.value), None)
+                })
+        })
+        .collect::<Result<Vec<Uint128>, ContractError>>()?;
+
+    Ok(SimulateStakeResponse {
+        lockup_term: lockup_term.value,
+        count,
+        start_time,
+        end_time,
+        reward_per_nft: to_asset_tokens(&campaign_info, reward_per_nft),
+        total_reward: to_asset_tokens(&campaign_info, total_reward),
+        annual_reward_per_nft: to_asset_tokens(&campaign_info, annual_reward_per_nft),
+    })
+}
+
+fn query_total_pending_reward(deps: Deps, env: Env) -> Result<Vec<AssetToken>, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
 
-    Ok(total_pending_reward)
+    // max time to calc = campaign_info.end_time
+    let mut current_time = env.block.time.seconds();
+    if campaign_info.end_time < env.block.time.seconds() {
+        current_time = campaign_info.end_time;
+    }
+
+    let total_pending_reward =
+        calc_total_pending_reward(deps.storage, &campaign_info, current_time)?;
+
+    Ok(to_asset_tokens(&campaign_info, total_pending_reward))
 }
 
-fn query_term_reward_rates(deps: Deps, term_value: u64) -> Result<Vec<RewardRate>, ContractError> {
-    let reward_rates = TERM_REWARD_RATES.load(deps.storage, term_value)?;
+fn query_reset_pool_progress(
+    deps: Deps,
+    env: Env,
+) -> Result<ResetPoolProgressResponse, ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(deps.storage)?;
+    let progress = RESET_POOL_PROGRESS.may_load(deps.storage)?;
+
+    // without reset pool in progress, remaining nfts are settled by reset until current time
+    let reset_time = progress.as_ref().map_or_else(
+        || env.block.time.seconds().min(campaign_info.end_time),
+        |progress| progress.reset_time,
+    );
+
+    let terms = campaign_info
+        .lockup_term
+        .iter()
+        .map(|term| {
+            let settled = progress
+                .as_ref()
+                .and_then(|progress| {
+                    progress
+                        .terms
+                        .iter()
+                        .find(|cursor| cursor.term_value == term.value)
+                })
+                .map_or(0, |cursor| cursor.settled);
+            let max = Bound::inclusive((reset_time, u64::MAX));
+            let remaining = TERM_EXPIRATIONS
+                .sub_prefix(term.value)
+                .keys(deps.storage, None, Some(max), Order::Ascending)
+                .count() as u64;
+
+            TermResetProgress {
+                term_value: term.value,
+                settled,
+                remaining,
+            }
+        })
+        .collect();
 
-    Ok(reward_rates)
+    Ok(ResetPoolProgressResponse {
+        reset_time: progress.map(|progress| progress.reset_time),
+        terms,
+    })
 }
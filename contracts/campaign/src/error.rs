@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use semver::Error as SemVerError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -30,12 +31,18 @@ pub enum ContractError {
     #[error("## Invalid LockupTerm ##")]
     InvalidLockupTerm {},
 
+    #[error("## Invalid early unstake penalty ##")]
+    InvalidEarlyUnstakePenalty {},
+
     #[error("## Insufficient balance ##")]
     InsufficientBalance {},
 
     #[error("## Too many token ids ##")]
     TooManyTokenIds {},
 
+    #[error("## Too many nfts, limit is {limit} ##")]
+    TooManyNfts { limit: u64 },
+
     #[error("## Invalid time to update ##")]
     InvalidTimeToUpdate {},
 
@@ -45,6 +52,27 @@ pub enum ContractError {
     #[error("## This NFT is still in staking period. Cannot unstake now ##")]
     InvalidTimeToUnStake {},
 
+    #[error("## No NFT to unstake ##")]
+    NoNftToUnStake {},
+
+    #[error("## Campaign is paused ##")]
+    Paused {},
+
+    #[error("## Campaign is not in emergency mode ##")]
+    NotInEmergency {},
+
+    #[error("## Campaign is cancelled ##")]
+    CampaignCancelled {},
+
+    #[error("## Invalid time to cancel campaign ##")]
+    InvalidTimeToCancel {},
+
+    #[error("## No pending transfer ##")]
+    NoPendingTransfer {},
+
+    #[error("## Pending transfer is expired ##")]
+    TransferExpired {},
+
     #[error("## Cannot deposit rewards to this pool ##")]
     InvalidTimeToAddReward {},
 
@@ -63,6 +91,62 @@ pub enum ContractError {
     #[error("## Empty reward pool ##")]
     EmptyReward {},
 
+    #[error("## Invalid collection ##")]
+    InvalidCollection {},
+
+    #[error("## Invalid trait multiplier ##")]
+    InvalidTraitMultiplier {},
+
     #[error("## Empty token_id: {key:?} ##")]
     EmptyNft { key: u64 },
+
+    #[error("## Cannot migrate from contract {previous_contract} ##")]
+    CannotMigrate { previous_contract: String },
+
+    #[error("## Cannot migrate from version {previous_version} to {new_version} ##")]
+    CannotMigrateVersion {
+        previous_version: String,
+        new_version: String,
+    },
+
+    #[error("## Invalid contract version: {0} ##")]
+    SemVer(String),
+
+    #[error("## Overflow in {operation} (lockup term: {term:?}, nft key: {key:?}) ##")]
+    Overflow {
+        operation: String,
+        term: Option<u64>,
+        key: Option<u64>,
+    },
+
+    #[error("## Underflow in {operation} (lockup term: {term:?}, nft key: {key:?}) ##")]
+    Underflow {
+        operation: String,
+        term: Option<u64>,
+        key: Option<u64>,
+    },
+}
+
+impl ContractError {
+    pub fn overflow(operation: &str, term: Option<u64>, key: Option<u64>) -> Self {
+        Self::Overflow {
+            operation: operation.to_string(),
+            term,
+            key,
+        }
+    }
+
+    pub fn underflow(operation: &str, term: Option<u64>, key: Option<u64>) -> Self {
+        Self::Underflow {
+            operation: operation.to_string(),
+            term,
+            key,
+        }
+    }
+}
+
+impl From<SemVerError> for ContractError {
+    fn from(err: SemVerError) -> Self {
+        Self::SemVer(err.to_string())
+    }
 }
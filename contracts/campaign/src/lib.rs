@@ -1,5 +1,6 @@
 pub mod contract;
 mod error;
+pub mod migrations;
 pub mod msg;
 pub mod state;
 pub mod utils;
@@ -0,0 +1,555 @@
+use cosmwasm_std::{Decimal256, Order, StdResult, Storage, Uint128};
+
+use crate::error::ContractError;
+use crate::state::{
+    AllowedCollection, CampaignInfo, NftInfo, RewardToken, StakerRewardAssetInfo, TermRewardIndex,
+    CAMPAIGN_INFO, NFTS, NFT_REWARD_CHECKPOINTS, NUMBER_OF_NFTS, STAKERS_INFO, STATE_VERSION,
+    TERM_EXPIRATIONS, TERM_REWARD_INDEXES, WEIGHT_PRECISION,
+};
+
+type Migration = fn(&mut dyn Storage) -> Result<(), ContractError>;
+
+// data migrations in order, state version after running MIGRATIONS[i] is i + 1
+const MIGRATIONS: &[Migration] = &[
+    rebuild_staking_by_term,
+    migrate_to_reward_tokens,
+    migrate_to_allowed_collections,
+    scale_weights,
+    migrate_to_reward_index,
+];
+
+/// Latest version of stored data
+pub const LATEST_STATE_VERSION: u64 = MIGRATIONS.len() as u64;
+
+/// Runs data migrations which have not been applied yet, returns the new state version
+pub fn migrate_state(storage: &mut dyn Storage) -> Result<u64, ContractError> {
+    // campaigns instantiated before state versioning have state version 0
+    let state_version = STATE_VERSION.may_load(storage)?.unwrap_or(0);
+
+    for migration in MIGRATIONS.iter().skip(state_version as usize) {
+        migration(storage)?;
+    }
+
+    STATE_VERSION.save(storage, &LATEST_STATE_VERSION)?;
+
+    Ok(LATEST_STATE_VERSION)
+}
+
+/// Staking many nfts in one message used to count only one nft in TOTAL_STAKING_BY_TERM
+/// and TERM_EXPIRATION_TIMES. Rebuild them from nfts which are not expired at the last
+/// change of reward rates.
+pub fn rebuild_staking_by_term(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let campaign_info: v1::CampaignInfo = v1::CAMPAIGN_INFO.load(storage)?;
+
+    let nfts = v1::NFTS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, nft_info)| nft_info))
+        .collect::<StdResult<Vec<v1::NftInfo>>>()?;
+
+    for term in campaign_info.lockup_term.iter() {
+        let mut term_reward_rates = v4::TERM_REWARD_RATES.load(storage, term.value)?;
+
+        // last time reward rates of this term were updated
+        let last_timestamp = match term_reward_rates.last() {
+            Some(reward_rate) => reward_rate.timestamp,
+            None => continue,
+        };
+
+        // nft can not be unstaked before its end time, so all active nfts are still in NFTS
+        let expiration_times = nfts
+            .iter()
+            .filter(|nft_info| {
+                nft_info.lockup_term.value == term.value && nft_info.end_time > last_timestamp
+            })
+            .map(|nft_info| nft_info.end_time)
+            .collect::<Vec<u64>>();
+
+        let total_staking = expiration_times.len() as u64;
+
+        if let Some(reward_rate) = term_reward_rates.last_mut() {
+            reward_rate.rate = total_staking;
+        }
+
+        v4::TERM_REWARD_RATES.save(storage, term.value, &term_reward_rates)?;
+        v4::TOTAL_STAKING_BY_TERM.save(storage, term.value, &total_staking)?;
+        v1::TERM_EXPIRATION_TIMES.save(storage, term.value, &expiration_times)?;
+    }
+
+    Ok(())
+}
+
+/// Campaign used to have only one reward token. Move it to reward_tokens and turn every
+/// reward amount of nfts and stakers into a list with one item.
+pub fn migrate_to_reward_tokens(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let campaign_info: v1::CampaignInfo = v1::CAMPAIGN_INFO.load(storage)?;
+
+    v2::CAMPAIGN_INFO.save(
+        storage,
+        &v2::CampaignInfo {
+            owner: campaign_info.owner,
+            campaign_name: campaign_info.campaign_name,
+            campaign_image: campaign_info.campaign_image,
+            campaign_description: campaign_info.campaign_description,
+            limit_per_staker: campaign_info.limit_per_staker,
+            reward_tokens: vec![RewardToken {
+                info: campaign_info.reward_token.info,
+                amount: campaign_info.reward_token.amount,
+                reward_per_second: Decimal256::from_ratio(campaign_info.reward_per_second, 1u128),
+                total_reward: campaign_info.total_reward,
+                total_reward_claimed: campaign_info.total_reward_claimed,
+                total_penalty: Uint128::zero(),
+            }],
+            allowed_collection: campaign_info.allowed_collection,
+            lockup_term: campaign_info.lockup_term,
+            start_time: campaign_info.start_time,
+            end_time: campaign_info.end_time,
+        },
+    )?;
+
+    let nfts = v1::NFTS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, nft_info) in nfts {
+        v2::NFTS.save(
+            storage,
+            key,
+            &v2::NftInfo {
+                key: nft_info.key,
+                token_id: nft_info.token_id,
+                owner: nft_info.owner,
+                pending_reward: vec![nft_info.pending_reward],
+                lockup_term: nft_info.lockup_term,
+                is_end_reward: nft_info.is_end_reward,
+                start_time: nft_info.start_time,
+                time_calc: nft_info.time_calc,
+                end_time: nft_info.end_time,
+            },
+        )?;
+    }
+
+    let stakers = v1::STAKERS_INFO
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (staker, staker_info) in stakers {
+        STAKERS_INFO.save(
+            storage,
+            staker,
+            &StakerRewardAssetInfo {
+                keys: staker_info.keys,
+                reward_debt: vec![staker_info.reward_debt],
+                reward_claimed: vec![staker_info.reward_claimed],
+            },
+        )?;
+    }
+
+    let previous_total_reward = v1::PREVIOUS_TOTAL_REWARD.load(storage)?;
+    v4::PREVIOUS_TOTAL_REWARD.save(storage, &vec![previous_total_reward])?;
+
+    Ok(())
+}
+
+/// Campaign used to have only one allowed collection. Move it to allowed_collections with
+/// weight 1, so every nft keeps the same share of reward, and record the collection and
+/// weight of nfts and their expiration times.
+pub fn migrate_to_allowed_collections(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let campaign_info: v2::CampaignInfo = v2::CAMPAIGN_INFO.load(storage)?;
+
+    CAMPAIGN_INFO.save(
+        storage,
+        &CampaignInfo {
+            owner: campaign_info.owner,
+            campaign_name: campaign_info.campaign_name,
+            campaign_image: campaign_info.campaign_image,
+            campaign_description: campaign_info.campaign_description,
+            limit_per_staker: campaign_info.limit_per_staker,
+            reward_tokens: campaign_info.reward_tokens,
+            allowed_collections: vec![AllowedCollection {
+                collection_addr: campaign_info.allowed_collection.to_string(),
+                weight: 1,
+            }],
+            lockup_term: campaign_info.lockup_term.clone(),
+            start_time: campaign_info.start_time,
+            end_time: campaign_info.end_time,
+            trait_multipliers: vec![],
+            cancelled: false,
+        },
+    )?;
+
+    let nfts = v2::NFTS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, nft_info) in nfts {
+        NFTS.save(
+            storage,
+            key,
+            &NftInfo {
+                key: nft_info.key,
+                token_id: nft_info.token_id,
+                collection_addr: campaign_info.allowed_collection.clone(),
+                weight: 1,
+                owner: nft_info.owner,
+                pending_reward: nft_info.pending_reward,
+                lockup_term: nft_info.lockup_term,
+                is_end_reward: nft_info.is_end_reward,
+                start_time: nft_info.start_time,
+                time_calc: nft_info.time_calc,
+                end_time: nft_info.end_time,
+            },
+        )?;
+    }
+
+    for term in campaign_info.lockup_term.iter() {
+        let expiration_times = v1::TERM_EXPIRATION_TIMES.load(storage, term.value)?;
+        v4::TERM_EXPIRATION_TIMES.save(
+            storage,
+            term.value,
+            &expiration_times
+                .into_iter()
+                .map(|end_time| v4::ExpirationTime {
+                    end_time,
+                    weight: 1,
+                })
+                .collect(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Weight of nft is stored in hundredths to support fractional trait multipliers. Scale
+/// weights of staked nfts and total weights by term, so every nft keeps the same share.
+pub fn scale_weights(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(storage)?;
+
+    let nfts = NFTS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (key, mut nft_info) in nfts {
+        nft_info.weight = nft_info
+            .weight
+            .checked_mul(WEIGHT_PRECISION)
+            .ok_or_else(|| ContractError::overflow("scale weights", Some(key.1), Some(key.0)))?;
+        NFTS.save(storage, key, &nft_info)?;
+    }
+
+    for term in campaign_info.lockup_term.iter() {
+        let mut term_reward_rates = v4::TERM_REWARD_RATES.load(storage, term.value)?;
+        for reward_rate in term_reward_rates.iter_mut() {
+            reward_rate.rate = reward_rate
+                .rate
+                .checked_mul(WEIGHT_PRECISION)
+                .ok_or_else(|| ContractError::overflow("scale weights", Some(term.value), None))?;
+        }
+
+        let mut expiration_times = v4::TERM_EXPIRATION_TIMES.load(storage, term.value)?;
+        for expiration in expiration_times.iter_mut() {
+            expiration.weight = expiration
+                .weight
+                .checked_mul(WEIGHT_PRECISION)
+                .ok_or_else(|| ContractError::overflow("scale weights", Some(term.value), None))?;
+        }
+
+        let total_staking = v4::TOTAL_STAKING_BY_TERM
+            .load(storage, term.value)?
+            .checked_mul(WEIGHT_PRECISION)
+            .ok_or_else(|| ContractError::overflow("scale weights", Some(term.value), None))?;
+
+        v4::TERM_REWARD_RATES.save(storage, term.value, &term_reward_rates)?;
+        v4::TERM_EXPIRATION_TIMES.save(storage, term.value, &expiration_times)?;
+        v4::TOTAL_STAKING_BY_TERM.save(storage, term.value, &total_staking)?;
+    }
+
+    Ok(())
+}
+
+/// Reward used to be calculated by browsing reward rates of each lockup term since the last
+/// reset pool. Calculate reward of all nfts at the last change of reward rates, which is not
+/// before time_calc of any nft, then continue with reward per weight from zero.
+pub fn migrate_to_reward_index(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let campaign_info: CampaignInfo = CAMPAIGN_INFO.load(storage)?;
+    let reward_per_second = campaign_info
+        .reward_tokens
+        .iter()
+        .map(|reward_token| {
+            Uint128::try_from(reward_token.reward_per_second.to_uint_floor())
+                .map_err(|_| ContractError::overflow("migrate to reward index", None, None))
+        })
+        .collect::<Result<Vec<Uint128>, _>>()?;
+    let previous_total_reward = v4::PREVIOUS_TOTAL_REWARD.load(storage)?;
+
+    for (i, term) in campaign_info.lockup_term.iter().enumerate() {
+        let term_reward_rates = v4::TERM_REWARD_RATES.load(storage, term.value)?;
+        let last_update = term_reward_rates
+            .last()
+            .map_or(campaign_info.start_time, |reward_rate| {
+                reward_rate.timestamp
+            });
+
+        let mut total_reward = reward_per_second
+            .iter()
+            .map(|reward_per_second| {
+                let total_reward = v4::calc_total_reward_in_rates(
+                    &term_reward_rates,
+                    *reward_per_second,
+                    term.percent,
+                );
+                Decimal256::from_ratio(total_reward, 1u128)
+            })
+            .collect::<Vec<Decimal256>>();
+
+        // total reward before the last reset pool is not kept by lockup term
+        if i == 0 {
+            for (total_reward, previous_total_reward) in
+                total_reward.iter_mut().zip(previous_total_reward.iter())
+            {
+                *total_reward = total_reward
+                    .checked_add(Decimal256::from_ratio(*previous_total_reward, 1u128))
+                    .map_err(|_| {
+                        ContractError::overflow("migrate to reward index", Some(term.value), None)
+                    })?;
+            }
+        }
+
+        let mut total_weight = 0u64;
+        let nft_count_by_term = NUMBER_OF_NFTS.may_load(storage, term.value)?.unwrap_or(0);
+        for key in 1..=nft_count_by_term {
+            let Some(mut nft_info) = NFTS.may_load(storage, (key, term.value))? else {
+                continue;
+            };
+
+            v4::calculate_reward(
+                &mut nft_info,
+                &term_reward_rates,
+                last_update,
+                campaign_info.end_time,
+                &reward_per_second,
+            );
+            NFTS.save(storage, (key, term.value), &nft_info)?;
+            NFT_REWARD_CHECKPOINTS.save(
+                storage,
+                (key, term.value),
+                &vec![Decimal256::zero(); reward_per_second.len()],
+            )?;
+
+            // nft which is not expired at the last change of reward rates still earns reward
+            if nft_info.end_time > last_update {
+                TERM_EXPIRATIONS.save(
+                    storage,
+                    (term.value, nft_info.end_time, key),
+                    &nft_info.weight,
+                )?;
+                total_weight = total_weight.checked_add(nft_info.weight).ok_or_else(|| {
+                    ContractError::overflow("migrate to reward index", Some(term.value), Some(key))
+                })?;
+            }
+        }
+
+        TERM_REWARD_INDEXES.save(
+            storage,
+            term.value,
+            &TermRewardIndex {
+                total_weight,
+                last_update,
+                reward_per_weight: vec![Decimal256::zero(); reward_per_second.len()],
+                total_reward,
+                total_dust: vec![Decimal256::zero(); reward_per_second.len()],
+            },
+        )?;
+
+        v4::TERM_REWARD_RATES.remove(storage, term.value);
+        v4::TOTAL_STAKING_BY_TERM.remove(storage, term.value);
+        v4::TERM_EXPIRATION_TIMES.remove(storage, term.value);
+    }
+
+    v4::PREVIOUS_TOTAL_REWARD.remove(storage);
+
+    Ok(())
+}
+
+/// Stored data before state version 2
+pub mod v1 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_storage_plus::{Item, Map};
+
+    use crate::state::{AssetToken, LockupTerm, NftKey};
+
+    #[cw_serde]
+    pub struct CampaignInfo {
+        pub owner: Addr,
+        pub campaign_name: String,
+        pub campaign_image: String,
+        pub campaign_description: String,
+        pub total_reward_claimed: Uint128,
+        pub total_reward: Uint128,
+        pub limit_per_staker: u64,
+        pub reward_token: AssetToken,
+        pub allowed_collection: Addr,
+        pub lockup_term: Vec<LockupTerm>,
+        pub reward_per_second: Uint128,
+        pub start_time: u64,
+        pub end_time: u64,
+    }
+
+    #[cw_serde]
+    pub struct NftInfo {
+        pub key: u64,
+        pub token_id: String,
+        pub owner: Addr,
+        pub pending_reward: Uint128,
+        pub lockup_term: LockupTerm,
+        pub is_end_reward: bool,
+        pub start_time: u64,
+        pub time_calc: u64,
+        pub end_time: u64,
+    }
+
+    #[cw_serde]
+    pub struct StakerRewardAssetInfo {
+        pub keys: Vec<NftKey>,
+        pub reward_debt: Uint128,
+        pub reward_claimed: Uint128,
+    }
+
+    pub const CAMPAIGN_INFO: Item<CampaignInfo> = Item::new("campaign_info");
+    pub const STAKERS_INFO: Map<Addr, StakerRewardAssetInfo> = Map::new("stakers_info");
+    pub const NFTS: Map<(u64, u64), NftInfo> = Map::new("nfts");
+    pub const PREVIOUS_TOTAL_REWARD: Item<Uint128> = Item::new("previous_total_reward");
+    pub const TERM_EXPIRATION_TIMES: Map<u64, Vec<u64>> = Map::new("expiration_times");
+}
+
+/// Stored data before state version 3
+pub mod v2 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{Addr, Uint128};
+    use cw_storage_plus::{Item, Map};
+
+    use crate::state::{LockupTerm, RewardToken};
+
+    #[cw_serde]
+    pub struct CampaignInfo {
+        pub owner: Addr,
+        pub campaign_name: String,
+        pub campaign_image: String,
+        pub campaign_description: String,
+        pub limit_per_staker: u64,
+        pub reward_tokens: Vec<RewardToken>,
+        pub allowed_collection: Addr,
+        pub lockup_term: Vec<LockupTerm>,
+        pub start_time: u64,
+        pub end_time: u64,
+    }
+
+    #[cw_serde]
+    pub struct NftInfo {
+        pub key: u64,
+        pub token_id: String,
+        pub owner: Addr,
+        pub pending_reward: Vec<Uint128>,
+        pub lockup_term: LockupTerm,
+        pub is_end_reward: bool,
+        pub start_time: u64,
+        pub time_calc: u64,
+        pub end_time: u64,
+    }
+
+    pub const CAMPAIGN_INFO: Item<CampaignInfo> = Item::new("campaign_info");
+    pub const NFTS: Map<(u64, u64), NftInfo> = Map::new("nfts");
+}
+
+/// Stored data before state version 5
+pub mod v4 {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::Uint128;
+    use cw_storage_plus::{Item, Map};
+
+    use crate::state::NftInfo;
+
+    #[cw_serde]
+    pub struct RewardRate {
+        pub timestamp: u64,
+        pub rate: u64, // total weight of nfts staked in lockup term
+    }
+
+    #[cw_serde]
+    pub struct ExpirationTime {
+        pub end_time: u64,
+        pub weight: u64,
+    }
+
+    pub const TERM_REWARD_RATES: Map<u64, Vec<RewardRate>> = Map::new("term_reward_rates");
+    pub const TOTAL_STAKING_BY_TERM: Map<u64, u64> = Map::new("total_staking_by_term");
+    pub const TERM_EXPIRATION_TIMES: Map<u64, Vec<ExpirationTime>> = Map::new("expiration_times");
+    pub const PREVIOUS_TOTAL_REWARD: Item<Vec<Uint128>> = Item::new("previous_total_reward");
+
+    // reward of nft from its time_calc to current_time by reward rates of its lockup term,
+    // reward rates must contain every change until current_time
+    pub fn calculate_reward(
+        nft: &mut NftInfo,
+        term_reward_rates: &[RewardRate],
+        current_time: u64,
+        end_time_campaign: u64,
+        reward_per_second: &[Uint128],
+    ) {
+        if nft.is_end_reward || current_time < nft.time_calc {
+            return;
+        }
+
+        let nft_start = nft.time_calc;
+        let nft_end = current_time.min(nft.end_time);
+
+        for (i, rate_obj) in term_reward_rates.iter().enumerate() {
+            if rate_obj.timestamp < nft_start || rate_obj.timestamp > nft_end || rate_obj.rate == 0
+            {
+                continue;
+            }
+
+            let next_timestamp = term_reward_rates
+                .get(i + 1)
+                .map_or(nft_end, |next| next.timestamp);
+            let duration = (next_timestamp.min(nft_end) - rate_obj.timestamp) as u128;
+
+            for (pending_reward, reward_per_second) in
+                nft.pending_reward.iter_mut().zip(reward_per_second)
+            {
+                let reward = duration
+                    .saturating_mul(reward_per_second.u128())
+                    .saturating_mul(nft.lockup_term.percent.u128())
+                    .saturating_mul(nft.weight as u128)
+                    .saturating_div(rate_obj.rate as u128)
+                    .saturating_div(100u128);
+                *pending_reward = pending_reward.saturating_add(Uint128::from(reward));
+            }
+        }
+
+        nft.time_calc = nft_end;
+        if nft.time_calc == nft.end_time || current_time >= end_time_campaign {
+            nft.is_end_reward = true;
+        }
+    }
+
+    // total reward of a lockup term in its reward rates
+    pub fn calc_total_reward_in_rates(
+        term_reward_rates: &[RewardRate],
+        reward_per_second: Uint128,
+        percent: Uint128,
+    ) -> Uint128 {
+        let mut total_reward: u128 = 0;
+
+        for rates in term_reward_rates.windows(2) {
+            let (current, next) = (&rates[0], &rates[1]);
+
+            if current.rate != 0 && next.timestamp > current.timestamp {
+                let duration = (next.timestamp - current.timestamp) as u128;
+                total_reward = total_reward.saturating_add(
+                    duration
+                        .saturating_mul(reward_per_second.u128())
+                        .saturating_mul(percent.u128())
+                        .saturating_div(100u128),
+                );
+            }
+        }
+
+        Uint128::from(total_reward)
+    }
+}
@@ -1,9 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::Addr;
+use cw20::Cw20ReceiveMsg;
+use cw721::Cw721ReceiveMsg;
 
 use crate::state::{
-    AssetToken, CampaignInfo, LockupTerm, NftInfo, NftKey, NftStake, RewardRate,
-    StakerRewardAssetInfo,
+    AllowedCollection, AssetToken, CampaignInfo, LockupTerm, NftInfo, NftKey, NftStake,
+    PendingTransfer, ResetPoolProgressResponse, SimulateStakeResponse, StakerPendingRewardResponse,
+    StakerRewardAssetInfo, StatusResponse, TermRewardIndex, TokenInfo, TraitMultiplier,
 };
 
 #[cw_serde]
@@ -16,41 +19,145 @@ pub struct InstantiateMsg {
     pub campaign_description: String,
 
     pub limit_per_staker: u64,
-    pub reward_token_info: AssetToken, // reward token
-    pub allowed_collection: String,    // staking collection nft
+    pub reward_tokens: Vec<TokenInfo>, // reward tokens, max 5
+    pub allowed_collections: Vec<AllowedCollection>, // staking collections nft, max 10
     pub lockup_term: Vec<LockupTerm>,  // flexible, 15days, 30days, 60days
 
     pub start_time: u64, // start time must be from T + 1
     pub end_time: u64,   // max 3 years
+
+    pub trait_multipliers: Vec<TraitMultiplier>, // reward multipliers by nft traits, max 20
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct UpdateCampaignInfo {
+    pub campaign_name: Option<String>,
+    pub campaign_image: Option<String>,
+    pub campaign_description: Option<String>,
+    pub limit_per_staker: Option<u64>, // only before campaign starts
+    pub lockup_term: Option<Vec<LockupTerm>>, // only before campaign starts
+    pub start_time: Option<u64>,       // only before campaign starts
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     AddRewardToken {
-        amount: Uint128,
+        reward_token: AssetToken,
     },
+    // owner can add reward by sending cw20 reward token to this campaign
+    Receive(Cw20ReceiveMsg),
     // user can stake 1 or many nfts to this campaign
     StakeNfts {
         stake_info: NftStake,
     },
+    // user can stake nft by sending it from allowed collection to this campaign
+    ReceiveNft(Cw721ReceiveMsg),
 
     UnStakeNft {
         unstake_info: NftKey,
         token_id: String,
     },
 
-    // user can claim reward
+    // user can unstake many nfts at once, nfts in staking period are skipped
+    UnStakeNfts {
+        keys: Vec<NftKey>,
+    },
+
+    // user can unstake all staked nfts, nfts in staking period are skipped
+    UnStakeAll {},
+
+    // user can move staked nft to a lockup term which is not shorter, reward is settled
+    ChangeLockupTerm {
+        key: NftKey,
+        new_term: u64,
+    },
+
+    // user can claim reward, all pending reward is claimed if amounts is none
+    // reward is sent to recipient, or stored reward recipient of staker, or staker
     ClaimReward {
-        amount: Uint128,
+        amounts: Option<Vec<AssetToken>>,
+        recipient: Option<String>,
+    },
+
+    // staker sets default address receiving claimed reward, none to receive by itself
+    SetRewardRecipient {
+        recipient: Option<String>,
     },
 
     WithdrawReward {},
 
-    ResetPool {},
+    // owner or admin can cancel campaign before it starts or while nobody has staked,
+    // all reward is refunded to owner
+    CancelCampaign {},
+
+    // admin accrues reward of lockup terms and settles at most limit expired nfts, reset is
+    // continued by next call until all lockup terms are updated
+    ResetPool {
+        limit: Option<u32>,
+    },
+
+    // owner proposes new owner, who must accept before expires_at
+    ProposeNewOwner {
+        owner: String,
+        expires_at: Option<u64>,
+    },
+
+    AcceptOwnership {},
+
+    CancelOwnershipProposal {},
 
-    UpdateAdmin {
+    // admin proposes new admin, who must accept before expires_at
+    ProposeNewAdmin {
         admin: String,
+        expires_at: Option<u64>,
+    },
+
+    AcceptAdmin {},
+
+    CancelAdminProposal {},
+
+    // owner can move end time of campaign and deposit reward token together
+    UpdateEndTime {
+        end_time: u64,
+        reward_token: Option<AssetToken>,
     },
+
+    // owner can update display metadata at any time and schedule before campaign starts
+    UpdateCampaign {
+        update_campaign: UpdateCampaignInfo,
+    },
+
+    // owner can update reward multipliers by nft traits, applied to nfts staked afterwards
+    UpdateTraitMultipliers {
+        trait_multipliers: Vec<TraitMultiplier>,
+    },
+
+    // admin can pause staking, claiming and adding reward
+    SetPaused {
+        paused: bool,
+    },
+
+    // admin can allow stakers to withdraw nfts regardless of lockup term
+    SetEmergency {
+        emergency: bool,
+    },
+
+    // staker withdraws all nfts in emergency mode, pending reward of nfts in staking period
+    // is forfeited
+    EmergencyWithdraw {},
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    AddRewardToken {},
+}
+
+#[cw_serde]
+pub enum ReceiveNftMsg {
+    StakeNft { lockup_term: u64 },
 }
 
 #[cw_serde]
@@ -59,6 +166,15 @@ pub enum QueryMsg {
     #[returns(CampaignInfo)]
     CampaignInfo {},
 
+    #[returns(StatusResponse)]
+    Status {},
+
+    #[returns(Option<PendingTransfer>)]
+    PendingOwner {},
+
+    #[returns(Option<PendingTransfer>)]
+    PendingAdmin {},
+
     #[returns(NftInfo)]
     NftInfo { nft_key: NftKey },
 
@@ -72,9 +188,29 @@ pub enum QueryMsg {
     #[returns(StakerRewardAssetInfo)]
     NftStaked { owner: Addr },
 
-    #[returns(Uint128)]
+    #[returns(Option<Addr>)]
+    RewardRecipient { owner: Addr },
+
+    // project reward of count nfts of collection staked at at_time (default now), assuming
+    // nobody else stakes or unstakes in the lockup term, nfts have traits of token_id if given
+    #[returns(SimulateStakeResponse)]
+    SimulateStake {
+        collection_addr: String,
+        token_id: Option<String>,
+        lockup_term: u64,
+        count: u64,
+        at_time: Option<u64>,
+    },
+
+    #[returns(StakerPendingRewardResponse)]
+    StakerPendingReward { owner: Addr },
+
+    #[returns(Vec<AssetToken>)]
     TotalPendingReward {},
 
-    #[returns(Vec<RewardRate>)]
-    TermRewardRates { term_value: u64 },
+    #[returns(TermRewardIndex)]
+    TermRewardIndex { term_value: u64 },
+
+    #[returns(ResetPoolProgressResponse)]
+    ResetPoolProgress {},
 }
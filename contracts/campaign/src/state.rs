@@ -1,12 +1,54 @@
 use std::fmt;
 
 use cosmwasm_schema::cw_serde; // attribute macro to (de)serialize and make schemas
-use cosmwasm_std::{Addr, Uint128}; // address type
+use cosmwasm_std::{Addr, Decimal, Decimal256, Uint128}; // address type
 use cw_storage_plus::{Item, Map}; // analog of Singletons for storage
 
 #[cw_serde]
 pub struct Config {
     pub admin: Addr,
+    #[serde(default)]
+    pub paused: bool, // staking, claiming and adding reward are blocked
+    #[serde(default)]
+    pub emergency: bool, // stakers can withdraw nfts regardless of lockup term
+}
+
+#[cw_serde]
+pub struct PendingTransfer {
+    pub new_address: Addr,       // proposed owner or admin
+    pub expires_at: Option<u64>, // proposal can not be accepted from this time
+}
+
+#[cw_serde]
+pub struct NftPendingReward {
+    pub key: NftKey,
+    pub token_id: String,
+    pub collection_addr: Addr,
+    pub pending_reward: Vec<AssetToken>,
+    pub unlocked: bool, // nft can be unstaked without penalty
+}
+
+#[cw_serde]
+pub struct StakerPendingRewardResponse {
+    pub total_pending_reward: Vec<AssetToken>, // claimable reward, reward debt and pending reward of unlocked nfts
+    pub nfts: Vec<NftPendingReward>,
+}
+
+#[cw_serde]
+pub struct SimulateStakeResponse {
+    pub lockup_term: u64,
+    pub count: u64,
+    pub start_time: u64,
+    pub end_time: u64, // end of lockup term, at most end time of campaign
+    pub reward_per_nft: Vec<AssetToken>,
+    pub total_reward: Vec<AssetToken>,
+    pub annual_reward_per_nft: Vec<AssetToken>, // reward per nft scaled to one year
+}
+
+#[cw_serde]
+pub struct StatusResponse {
+    pub paused: bool,
+    pub emergency: bool,
 }
 
 #[cw_serde]
@@ -36,10 +78,84 @@ impl fmt::Display for AssetToken {
     }
 }
 
+#[cw_serde]
+pub struct RewardToken {
+    pub info: TokenInfo,
+    pub amount: Uint128,               // reward balance of campaign
+    pub reward_per_second: Decimal256, // rounded down, so reward never exceeds amount
+    pub total_reward: Uint128,         // default 0
+    pub total_reward_claimed: Uint128, // default 0
+    #[serde(default)]
+    pub total_penalty: Uint128, // reward forfeited by early unstake, default 0
+}
+
+#[cw_serde]
+pub struct AllowedCollection {
+    pub collection_addr: String,
+    pub weight: u64, // reward weight of each nft in this collection
+}
+
+impl fmt::Display for AllowedCollection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.collection_addr, self.weight)
+    }
+}
+
+#[cw_serde]
+pub struct TraitMultiplier {
+    pub trait_type: String,
+    pub value: String,
+    pub multiplier: Decimal, // reward weight of nft having this trait is multiplied by it
+}
+
+impl fmt::Display for TraitMultiplier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}: {}", self.trait_type, self.value, self.multiplier)
+    }
+}
+
+#[cw_serde]
+pub struct Trait {
+    pub display_type: Option<String>,
+    pub trait_type: String,
+    pub value: String,
+}
+
+// see: https://docs.opensea.io/docs/metadata-standards
+#[cw_serde]
+#[derive(Default)]
+pub struct Metadata {
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub external_url: Option<String>,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub attributes: Option<Vec<Trait>>,
+    pub background_color: Option<String>,
+    pub animation_url: Option<String>,
+    pub youtube_url: Option<String>,
+    /// This is how much the minter takes as a cut when sold
+    /// royalties are owed on this token if it is Some
+    pub royalty_percentage: Option<u64>,
+    /// The payment address, may be different to or the same
+    /// as the minter addr
+    /// question: how do we validate this?
+    pub royalty_payment_address: Option<String>,
+}
+
+#[cw_serde]
+pub struct EarlyUnstakePenalty {
+    pub percent: Uint128,          // percent of pending reward of nft is forfeited
+    pub decay: bool,               // percent decays linearly to 0 at the end of lockup term
+    pub recipient: Option<String>, // forfeited reward is sent to recipient, or kept in pool if None
+}
+
 #[cw_serde]
 pub struct LockupTerm {
     pub value: u64,
     pub percent: Uint128,
+    #[serde(default)]
+    pub early_unstake_penalty: Option<EarlyUnstakePenalty>, // nft can not be unstaked early if None
 }
 
 impl fmt::Display for LockupTerm {
@@ -54,32 +170,39 @@ pub struct CampaignInfo {
     pub campaign_name: String,
     pub campaign_image: String,
     pub campaign_description: String,
-    pub total_reward_claimed: Uint128, // default 0
-    pub total_reward: Uint128,         // default 0
-    pub limit_per_staker: u64,         // max nft can stake
-    pub reward_token: AssetToken,      // reward token
-    pub allowed_collection: Addr,      // staking collection nft
+    pub limit_per_staker: u64,                       // max nft can stake
+    pub reward_tokens: Vec<RewardToken>,             // reward tokens
+    pub allowed_collections: Vec<AllowedCollection>, // staking collections nft
     pub lockup_term: Vec<LockupTerm>,
-    pub reward_per_second: Uint128,
     pub start_time: u64, // start time must be from T + 1
     pub end_time: u64,   // max 3 years
+    #[serde(default)]
+    pub trait_multipliers: Vec<TraitMultiplier>, // multipliers by nft traits
+    #[serde(default)]
+    pub cancelled: bool, // cancelled campaign rejects every execute
 }
 
 #[cw_serde]
 pub struct NftInfo {
     pub key: u64,
     pub token_id: String,
+    pub collection_addr: Addr, // collection of nft
+    pub weight: u64,           // reward weight of nft in its lockup term, in hundredths
     pub owner: Addr,
-    pub pending_reward: Uint128,
-    pub lockup_term: LockupTerm, // value = seconds
+    pub pending_reward: Vec<Uint128>, // same order as reward_tokens of campaign
+    pub lockup_term: LockupTerm,      // value = seconds
     pub is_end_reward: bool,
     pub start_time: u64,
     pub time_calc: u64,
     pub end_time: u64,
 }
 
+// weight of nft without trait multipliers = collection weight * WEIGHT_PRECISION
+pub const WEIGHT_PRECISION: u64 = 100;
+
 #[cw_serde]
 pub struct NftStake {
+    pub collection_addr: String,
     pub token_ids: Vec<String>,
     pub lockup_term: u64,
 }
@@ -90,6 +213,12 @@ pub struct NftKey {
     pub lockup_term: u64,
 }
 
+impl fmt::Display for NftKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.lockup_term)
+    }
+}
+
 #[cw_serde]
 pub struct NftUnStake {
     pub key: u64,
@@ -98,22 +227,52 @@ pub struct NftUnStake {
 }
 
 #[cw_serde]
-pub struct RewardRate {
-    pub timestamp: u64,
-    pub rate: u64,
+pub struct TermRewardIndex {
+    pub total_weight: u64, // total weight of nfts earning reward in lockup term
+    pub last_update: u64,  // reward is accrued until this time
+    pub reward_per_weight: Vec<Decimal256>, // accrued reward per weight, same order as reward_tokens
+    pub total_reward: Vec<Decimal256>,      // total reward accrued by lockup term
+    #[serde(default)]
+    pub total_dust: Vec<Decimal256>, // fraction of reward dropped by nfts leaving lockup term
 }
 
-impl fmt::Display for RewardRate {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}: {}", self.timestamp, self.rate)
-    }
+#[cw_serde]
+pub struct ExpirationTime {
+    pub end_time: u64,
+    pub key: u64,
+    pub weight: u64, // weight of nft which is expired at end_time
+}
+
+#[cw_serde]
+pub struct ResetPoolProgress {
+    pub reset_time: u64, // reward of lockup terms is accrued until this time by reset pool
+    pub terms: Vec<ResetPoolCursor>,
+}
+
+#[cw_serde]
+pub struct ResetPoolCursor {
+    pub term_value: u64,
+    pub settled: u64, // expired nfts settled by reset pool in lockup term
+}
+
+#[cw_serde]
+pub struct ResetPoolProgressResponse {
+    pub reset_time: Option<u64>, // none if no reset pool is in progress
+    pub terms: Vec<TermResetProgress>,
+}
+
+#[cw_serde]
+pub struct TermResetProgress {
+    pub term_value: u64,
+    pub settled: u64,
+    pub remaining: u64, // expired nfts which are not settled until reset time
 }
 
 #[cw_serde]
 pub struct StakerRewardAssetInfo {
     pub keys: Vec<NftKey>,
-    pub reward_debt: Uint128, // can claim reward.
-    pub reward_claimed: Uint128,
+    pub reward_debt: Vec<Uint128>, // can claim reward, same order as reward_tokens of campaign
+    pub reward_claimed: Vec<Uint128>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -121,15 +280,33 @@ pub const CONFIG: Item<Config> = Item::new("config");
 // campaign info
 pub const CAMPAIGN_INFO: Item<CampaignInfo> = Item::new("campaign_info");
 
+// proposed owner of campaign and admin of config, waiting to be accepted
+pub const PENDING_OWNER: Item<PendingTransfer> = Item::new("pending_owner");
+pub const PENDING_ADMIN: Item<PendingTransfer> = Item::new("pending_admin");
+
 // Mapping from staker address to staked nft.
 pub const STAKERS_INFO: Map<Addr, StakerRewardAssetInfo> = Map::new("stakers_info");
 
+// address receiving claimed reward of staker instead of staker itself
+pub const REWARD_RECIPIENTS: Map<Addr, Addr> = Map::new("reward_recipients");
+
 // list nft staked
 pub const NFTS: Map<(u64, u64), NftInfo> = Map::new("nfts");
 pub const NUMBER_OF_NFTS: Map<u64, u64> = Map::new("number_of_nfts");
 
-pub const TERM_REWARD_RATES: Map<u64, Vec<RewardRate>> = Map::new("term_reward_rates");
-pub const TOTAL_STAKING_BY_TERM: Map<u64, u64> = Map::new("total_staking_by_term");
-pub const TERM_EXPIRATION_TIMES: Map<u64, Vec<u64>> = Map::new("expiration_times");
+pub const TERM_REWARD_INDEXES: Map<u64, TermRewardIndex> = Map::new("term_reward_indexes");
+// weight of staked nfts by (lockup term, end time, nft key), ordered by end time
+// entry is removed when nft is settled at its end time by any execute updating lockup term,
+// so it does not grow with elapsed time and needs no compaction by ResetPool
+pub const TERM_EXPIRATIONS: Map<(u64, u64, u64), u64> = Map::new("term_expirations");
+// reward per weight of lockup term when reward of nft was last calculated
+pub const NFT_REWARD_CHECKPOINTS: Map<(u64, u64), Vec<Decimal256>> =
+    Map::new("nft_reward_checkpoints");
+// fraction of reward earned by nft which is not moved to its pending reward yet
+pub const NFT_REWARD_DUST: Map<(u64, u64), Vec<Decimal256>> = Map::new("nft_reward_dust");
+
+// reset pool which is not completed yet, continued by next reset pool
+pub const RESET_POOL_PROGRESS: Item<ResetPoolProgress> = Item::new("reset_pool_progress");
 
-pub const PREVIOUS_TOTAL_REWARD: Item<Uint128> = Item::new("previous_total_reward");
+// version of stored data, used to run data migrations in order
+pub const STATE_VERSION: Item<u64> = Item::new("state_version");
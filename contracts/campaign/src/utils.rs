@@ -1,6 +1,10 @@
-use cosmwasm_std::{DivideByZeroError, OverflowError, Uint128};
+use cosmwasm_std::{Decimal256, OverflowError, Uint128, Uint256};
 
-use crate::state::{NftInfo, RewardRate};
+use crate::error::ContractError;
+use crate::state::{ExpirationTime, LockupTerm, NftInfo, TermRewardIndex};
+
+/// Key of expired nft and reward per weight of its lockup term at its end time
+pub type ExpiredNft = (u64, Vec<Decimal256>);
 
 /// Calculates the reward amount
 pub fn add_reward(current_reward: Uint128, calc_reward: Uint128) -> Result<Uint128, OverflowError> {
@@ -11,158 +15,201 @@ pub fn sub_reward(current_reward: Uint128, calc_reward: Uint128) -> Result<Uint1
     current_reward.checked_sub(calc_reward)
 }
 
-pub fn calc_reward_in_time(
-    start_time: u64,
-    end_time: u64,
-    reward_per_second: Uint128,
-    percent: Uint128,
-    nft_count: u128,
-) -> Result<Uint128, DivideByZeroError> {
-    let diff_time = end_time.checked_sub(start_time).unwrap();
-
-    let mul_reward = Uint128::from(diff_time)
-        .checked_mul(reward_per_second)
-        .and_then(|res| res.checked_mul(percent))
-        .unwrap();
-
-    let divisor = Uint128::from(100u128)
-        .checked_mul(Uint128::from(nft_count))
-        .unwrap();
-
-    mul_reward.checked_div(divisor)
-}
-
-pub fn update_reward_rate(
-    mut arr_reward_rate: Vec<RewardRate>,
-    total: u64,
-    timestamp: u64,
-    change: i64,
-) -> (Vec<RewardRate>, u64) {
-    // Ensure the total doesn't go below zero
-    let result = (total as i64).saturating_add(change);
-    let total_nft = if result <= 0 { 0 } else { result } as u64;
-
-    if let Some(reward) = arr_reward_rate
-        .iter_mut()
-        .find(|item| item.timestamp == timestamp)
-    {
-        reward.rate = total_nft;
-    } else {
-        arr_reward_rate.push(RewardRate {
-            timestamp,
-            rate: total_nft,
-        });
+/// Accrues reward of lockup term from its last update to current_time
+pub fn accrue_reward(
+    index: &mut TermRewardIndex,
+    current_time: u64,
+    term: &LockupTerm,
+    reward_per_second: &[Decimal256],
+) -> Result<(), ContractError> {
+    if current_time <= index.last_update {
+        return Ok(());
     }
 
-    // Sort arrRewardRate by time in ascending order
-    arr_reward_rate.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
-
-    (arr_reward_rate, total_nft)
-}
-
-pub fn stake_nft(
-    expiration_times: Vec<u64>,
-    mut arr_reward_rate: Vec<RewardRate>,
-    total: u64,
-    nft: NftInfo,
-    timestamp: u64,
-) -> (Vec<u64>, Vec<RewardRate>, u64) {
-    let mut new_expiration_times: Vec<u64> = vec![];
-    let mut total_nft = total;
-
-    for &end_time in &expiration_times {
-        if end_time <= timestamp {
-            let (updated_reward_rate, t) =
-                update_reward_rate(arr_reward_rate, total_nft, end_time, -1);
-            arr_reward_rate = updated_reward_rate;
-            total_nft = t;
-        } else {
-            new_expiration_times.push(end_time);
+    if index.total_weight != 0 {
+        let overflow = || ContractError::overflow("accrue reward", Some(term.value), None);
+        let duration = Uint256::from(current_time - index.last_update);
+        let total_weight = Uint256::from(index.total_weight);
+
+        for ((reward_per_weight, total_reward), reward_per_second) in index
+            .reward_per_weight
+            .iter_mut()
+            .zip(index.total_reward.iter_mut())
+            .zip(reward_per_second)
+        {
+            // reward of lockup term = duration * reward_per_second * percent / 100, shared by
+            // total weight. Reward per weight is rounded up, so nft with the whole weight
+            // earns the whole reward, reward of each nft is rounded down
+            let numerator = duration
+                .checked_mul(reward_per_second.atomics())
+                .and_then(|res| res.checked_mul(Uint256::from(term.percent)))
+                .map_err(|_| overflow())?;
+            let denominator = Uint256::from(100u128) * total_weight;
+            let increase = numerator
+                .checked_add(denominator - Uint256::one())
+                .map_err(|_| overflow())?
+                / denominator;
+            let increase = Decimal256::new(increase);
+
+            *reward_per_weight = reward_per_weight
+                .checked_add(increase)
+                .map_err(|_| overflow())?;
+            *total_reward = increase
+                .checked_mul(Decimal256::from_ratio(index.total_weight, 1u64))
+                .and_then(|res| total_reward.checked_add(res))
+                .map_err(|_| overflow())?;
         }
     }
 
-    new_expiration_times.push(nft.end_time);
-
-    let (final_reward_rate, new_total) =
-        update_reward_rate(arr_reward_rate, total_nft, timestamp, 1);
+    index.last_update = current_time;
 
-    (new_expiration_times, final_reward_rate, new_total)
+    Ok(())
 }
 
-pub fn calculate_reward(
-    mut nft: NftInfo,
-    mut term_reward_rates: Vec<RewardRate>,
-    expiration_times: Vec<u64>,
-    total: u64,
+/// Accrues reward of lockup term until current_time, nfts in expirations stop earning at
+/// their end time. expirations must be expired until current_time, in order of end time.
+/// Returns key and reward per weight of lockup term at end time of each expired nft
+pub fn expire_nfts(
+    index: &mut TermRewardIndex,
+    expirations: &[ExpirationTime],
     current_time: u64,
     end_time_campaign: u64,
-    reward_per_second: Uint128,
-) -> (NftInfo, Vec<RewardRate>, u64, Vec<u64>) {
-    if nft.is_end_reward {
-        return (nft, term_reward_rates, total, expiration_times);
-    }
-    let mut new_expiration_times: Vec<u64> = vec![];
-    let mut total_nft = total;
-
-    for &end_time in &expiration_times {
-        if end_time <= current_time {
-            let (updated_reward_rate, t) =
-                update_reward_rate(term_reward_rates, total_nft, end_time, -1);
-            term_reward_rates = updated_reward_rate;
-            total_nft = t;
-        } else {
-            new_expiration_times.push(end_time);
-        }
+    term: &LockupTerm,
+    reward_per_second: &[Decimal256],
+) -> Result<Vec<ExpiredNft>, ContractError> {
+    let mut expired = vec![];
+
+    for expiration in expirations {
+        // reward is not accrued after campaign is ended
+        accrue_reward(
+            index,
+            expiration.end_time.min(end_time_campaign),
+            term,
+            reward_per_second,
+        )?;
+        index.total_weight = index
+            .total_weight
+            .checked_sub(expiration.weight)
+            .ok_or_else(|| {
+                ContractError::underflow("expire nft", Some(term.value), Some(expiration.key))
+            })?;
+        expired.push((expiration.key, index.reward_per_weight.clone()));
     }
 
-    let (final_reward_rate, _) = update_reward_rate(term_reward_rates, total_nft, current_time, 0);
-    term_reward_rates = final_reward_rate;
+    accrue_reward(
+        index,
+        current_time.min(end_time_campaign),
+        term,
+        reward_per_second,
+    )?;
 
-    let mut reward: u128 = 0;
-
-    // Sort arrRewardRate by time in ascending order
-    term_reward_rates.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    Ok(expired)
+}
 
+/// Moves reward earned by nft since its checkpoint to its pending reward, the fraction left is
+/// kept in dust. reward_per_weight is reward per weight of lockup term at current_time, or at
+/// end time of nft if it is expired
+pub fn calculate_reward(
+    nft: &mut NftInfo,
+    checkpoint: &mut [Decimal256],
+    dust: &mut [Decimal256],
+    reward_per_weight: &[Decimal256],
+    current_time: u64,
+    end_time_campaign: u64,
+) -> Result<(), ContractError> {
     // If the required timeline is before the NFT is staked, return 0
     if current_time < nft.time_calc {
-        return (nft, term_reward_rates, total_nft, new_expiration_times);
+        return Ok(());
     }
 
-    let nft_start = nft.time_calc;
-    let nft_end = current_time.min(nft.end_time);
-
-    // Browse each timeline with reward rate changes
-    for i in 0..term_reward_rates.len() {
-        let rate_obj = &term_reward_rates[i];
-
-        if rate_obj.timestamp >= nft_start && rate_obj.timestamp <= nft_end {
-            let next_timestamp = if i + 1 < term_reward_rates.len() {
-                term_reward_rates[i + 1].timestamp
-            } else {
-                nft_end
-            };
-            let duration = (next_timestamp.min(nft_end) - rate_obj.timestamp) as u128;
-            if rate_obj.rate != 0 {
-                let additional_reward = duration
-                    .saturating_mul(reward_per_second.u128())
-                    .saturating_mul(nft.lockup_term.percent.u128())
-                    .saturating_div(rate_obj.rate as u128)
-                    .saturating_div(100u128);
-                reward = reward.saturating_add(additional_reward);
-            }
+    // expired nft has already earned all of its reward
+    if nft.time_calc < nft.end_time {
+        let (term, key) = (Some(nft.lockup_term.value), Some(nft.key));
+        let weight = Decimal256::from_ratio(nft.weight, 1u64);
+
+        for (((pending_reward, checkpoint), dust), reward_per_weight) in nft
+            .pending_reward
+            .iter_mut()
+            .zip(checkpoint.iter_mut())
+            .zip(dust.iter_mut())
+            .zip(reward_per_weight)
+        {
+            let reward = reward_per_weight
+                .checked_sub(*checkpoint)
+                .map_err(|_| ContractError::underflow("calculate reward", term, key))?
+                .checked_mul(weight)
+                .and_then(|res| res.checked_add(*dust))
+                .map_err(|_| ContractError::overflow("calculate reward", term, key))?;
+            let whole_reward = reward.to_uint_floor();
+            *pending_reward = Uint128::try_from(whole_reward)
+                .ok()
+                .and_then(|whole_reward| add_reward(*pending_reward, whole_reward).ok())
+                .ok_or_else(|| ContractError::overflow("add pending reward", term, key))?;
+            *dust = reward - Decimal256::from_ratio(whole_reward, 1u128);
+            *checkpoint = *reward_per_weight;
         }
     }
 
-    // update pending reward
-    nft.pending_reward = add_reward(nft.pending_reward, Uint128::from(reward)).unwrap();
-
     // update time calc
-    nft.time_calc = nft_end;
+    nft.time_calc = current_time.min(nft.end_time);
 
-    // if nft is end reward then update status nft
-    if nft.time_calc == nft.end_time || current_time >= end_time_campaign {
-        nft.is_end_reward = true;
-    }
+    // nft is end reward at the end of its lockup term or campaign
+    nft.is_end_reward = nft.time_calc == nft.end_time || current_time >= end_time_campaign;
+
+    Ok(())
+}
 
-    (nft, term_reward_rates, total_nft, new_expiration_times)
+/// Projects reward of nft staked at its start_time until the end of its lockup term, after
+/// count nfts like it are staked in the lockup term and nobody else stakes or unstakes
+pub fn simulate_stake(
+    mut nft: NftInfo,
+    count: u64,
+    mut index: TermRewardIndex,
+    expirations: &[ExpirationTime],
+    end_time_campaign: u64,
+    reward_per_second: &[Decimal256],
+) -> Result<Vec<Uint128>, ContractError> {
+    let term = nft.lockup_term.clone();
+
+    // nfts expired before the simulated nfts are staked
+    let staked = expirations.partition_point(|expiration| expiration.end_time <= nft.start_time);
+    expire_nfts(
+        &mut index,
+        &expirations[..staked],
+        nft.start_time,
+        end_time_campaign,
+        &term,
+        reward_per_second,
+    )?;
+
+    let mut checkpoint = index.reward_per_weight.clone();
+    let mut dust = vec![Decimal256::zero(); checkpoint.len()];
+    index.total_weight = nft
+        .weight
+        .checked_mul(count)
+        .and_then(|weight| index.total_weight.checked_add(weight))
+        .ok_or_else(|| ContractError::overflow("simulate stake", Some(term.value), None))?;
+
+    let current_time = nft.end_time.min(end_time_campaign);
+    let expirations = &expirations[staked..];
+    let expired = expirations.partition_point(|expiration| expiration.end_time <= current_time);
+    expire_nfts(
+        &mut index,
+        &expirations[..expired],
+        current_time,
+        end_time_campaign,
+        &term,
+        reward_per_second,
+    )?;
+
+    calculate_reward(
+        &mut nft,
+        &mut checkpoint,
+        &mut dust,
+        &index.reward_per_weight,
+        current_time,
+        end_time_campaign,
+    )?;
+
+    Ok(nft.pending_reward)
 }